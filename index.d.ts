@@ -1,5 +1,31 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+/** A problem met while reading a file, its keys may be missing from the result */
+export interface Diagnostic {
+  filePath: string
+  /** Where the problem is in the file, missing when it is about the whole file */
+  span?: DiagnosticSpan
+  message: string
+  severity: DiagnosticSeverity
+}
+
+/**
+ * An `Error` means the file was skipped or only partly read, e.g. a syntax error,
+ * a `Warning` is an import which could not be followed
+ */
+export declare const enum DiagnosticSeverity {
+  Error = 'Error',
+  Warning = 'Warning'
+}
+
+/** `start` and `end` are byte offsets, `line` and `column` of the start are 1-based */
+export interface DiagnosticSpan {
+  start: number
+  end: number
+  line: number
+  column: number
+}
+
 export interface I18NPackage {
   packagePath: string
   members: Array<Member>
@@ -27,8 +53,10 @@ export interface I18NPackage {
  *     const hocWrapper = withTranslation(ns)(Component);
  * 6. ObjectMemberT
  *     : i18n.t('key', { ns: 'ns' })
+ * 7. FixedT
+ *     : const t = i18n.getFixedT(null, 'ns', 'keyPrefix');
  *
- * Those 6 types are calls `t` method to translate except `TransComp`
+ * Those 7 types are calls `t` method to translate except `TransComp`
  */
 export declare const enum I18nType {
   Hook = 'Hook',
@@ -36,20 +64,81 @@ export declare const enum I18nType {
   TransComp = 'TransComp',
   TranslationComp = 'TranslationComp',
   HocWrapper = 'HocWrapper',
-  ObjectMemberT = 'ObjectMemberT'
+  ObjectMemberT = 'ObjectMemberT',
+  FixedT = 'FixedT'
+}
+
+export interface KeyDetail {
+  key: string
+  locations: Array<KeyLocation>
+  /** The default value the key is used with, e.g. `t('save', 'Save')` or the `Trans` children */
+  defaultValue?: string
+}
+
+/** Where a key was read from, `line` and `column` are 1-based */
+export interface KeyLocation {
+  filePath: string
+  line: number
+  column: number
+  type: I18nType
+}
+
+/**
+ * A method of the i18n instance referencing a key, i18n.exists('key') or
+ * i18n.getResource(lng, 'ns', 'key')
+ */
+export interface KeyMethod {
+  name: string
+  /** Index of the key argument */
+  keyIndex: number
+  /** Index of the namespace argument, the namespace is read from the options after the key when omitted */
+  nsIndex?: number
 }
 
 export interface Member {
   name: string
   type: I18nType
   ns?: string
+  keyPrefix?: string
+  /** Methods of an `ObjectMemberT` member reading keys, defaults to `exists` and `getResource` */
+  keyMethods?: Array<KeyMethod>
+  /** Index of the key argument of a `TMethod` member, defaults to 0 */
+  keyIndex?: number
+  /** Index of the namespace argument of a `TMethod` member, read from the options when omitted */
+  nsIndex?: number
+  /** Index of the options argument of a `TMethod` member, defaults to the one after the key */
+  optionsIndex?: number
 }
 
 export interface Payload {
   tsconfigPath: string
+  /** Entry files, or glob patterns matching them like `src/pages/*.tsx` */
   entryPaths: Array<string>
+  /**
+   * Glob patterns of source files scanned as entries on top of `entryPaths`,
+   * every source file of a directory is scanned whether it is imported or not
+   */
+  include?: Array<string>
+  /** Glob patterns of files left out of the matched entries, matched files in `node_modules` are always left out */
+  exclude?: Array<string>
   externals: Array<string>
   extendI18NPackages?: Array<I18NPackage>
+  /** Number of threads used to parse and walk files, defaults to the number of CPUs */
+  threads?: number
+  /** Same as i18next `keySeparator`, `false` keeps the keys flat, defaults to `.` */
+  keySeparator?: string | boolean
+  /** Same as i18next `nsSeparator`, `false` never reads a namespace from the key, defaults to `:` */
+  nsSeparator?: string | boolean
+  /** Namespaces a key prefix before `nsSeparator` may name, only a key looking like an object path is split when omitted */
+  namespaces?: Array<string>
+  /** Languages whose plural forms are collected for keys used with `count`, defaults to `["en"]` */
+  languages?: Array<string>
+  /** Same as react-i18next `transKeepBasicHtmlNodesFor`, defaults to `["br", "strong", "i", "p"]` */
+  transKeepBasicHtmlNodesFor?: Array<string>
+  /** Extractors reading keys out of templates which are not JavaScript, e.g. `.html` or `.hbs` */
+  templateExtractors?: Array<TemplateExtractor>
+  /** Throws a `PARSE_ERROR` when a file cannot be read or parsed instead of skipping its keys */
+  failOnParseError?: boolean
 }
 
 /**
@@ -65,3 +154,101 @@ export interface Payload {
  *    or bypass from another function wrapped by the i18n function
  */
 export declare function scan(payload: Payload): Record<string, Array<string>>
+
+/**
+ * Same as `scan` but every key carries the file path, line, column
+ * and the i18n member type that produced it
+ */
+export declare function scanDetailed(payload: Payload): ScanDetailedResult
+
+/**
+ * Same as `scan` but the keys of every namespace are nested by `keySeparator`,
+ * the way they are stored in the resource files
+ */
+export declare function scanNested(payload: Payload): Record<string, any>
+
+export interface ScanDetailedResult {
+  /** { namespace: [{ key, locations }] } */
+  namespaces: Record<string, Array<KeyDetail>>
+  /** Files which could not be read or parsed and imports which could not be resolved */
+  diagnostics: Array<Diagnostic>
+}
+
+export interface ResourceOptions {
+  /** Where the locale files live, e.g. `locales/{{lng}}/{{ns}}.json` */
+  pathPattern: string
+  /** Languages to load, every language matched by `path_pattern` when omitted */
+  languages?: Array<string>
+  /** Resource namespace holding the keys collected without a namespace, defaults to `translation` */
+  defaultNamespace?: string
+}
+
+/**
+ * Scans the project and reports the keys found in the locale resources
+ * that are never referenced, `{ lng: { ns: [key] } }`
+ */
+export declare function findUnusedKeys(payload: Payload, resources: ResourceOptions): Record<string, Record<string, Array<string>>>
+
+/**
+ * Scans the project and reports the collected keys without a translation
+ * in the locale resources, `{ lng: { ns: [{ key, locations }] } }`
+ */
+export declare function findMissingKeys(payload: Payload, resources: ResourceOptions): Record<string, Record<string, Array<KeyDetail>>>
+
+export interface WriteOptions {
+  /** Value given to the keys without a translation yet, defaults to an empty string */
+  defaultValue?: string
+  /** Removes the translations of keys that are no longer collected */
+  removeUnused?: boolean
+  /** Sorts the keys alphabetically, the existing order is kept otherwise */
+  sortKeys?: boolean
+  /** Language whose new keys are filled with the default value read from the code when there is one */
+  sourceLanguage?: string
+}
+
+/**
+ * Scans the project and merges the collected keys into the locale resources,
+ * missing files are created, returns the written file paths
+ */
+export declare function writeResources(payload: Payload, resources: ResourceOptions, options?: WriteOptions | undefined | null): Array<string>
+
+/** Reads keys out of files which are not JavaScript, e.g. Angular or Handlebars templates */
+export interface TemplateExtractor {
+  /** Glob patterns of the template files, e.g. `src/app/*.html` */
+  files: Array<string>
+  /** Pipes translating the quoted key before them, `{{ 'key' | translate }}`, defaults to `["translate"]` */
+  pipes?: Array<string>
+  /** Helpers called with a quoted key, `{{t 'key'}}` or `{{ i18n.t('key') }}`, defaults to `["t", "i18n.t"]` */
+  helpers?: Array<string>
+  /**
+   * Regular expressions matching a key, the key is read from the `key` group or the first group
+   * and the namespace from the `ns` group
+   */
+  patterns?: Array<string>
+}
+
+/** Options of the config file, shared by the JS API and the command line */
+export interface ScanConfig {
+  /** The file the options were read from */
+  configPath: string
+  payload: Payload
+  /** Locale resources of `findUnusedKeys`, `findMissingKeys` and `writeResources` */
+  resources?: ResourceOptions
+  /** Options of `writeResources` */
+  write?: WriteOptions
+  /** What the command line prints */
+  output?: OutputOptions
+}
+
+export interface OutputOptions {
+  /** `keys`, `detailed` or `nested`, the result of `scan`, `scanDetailed` or `scanNested` */
+  format?: string
+  /** Writes the result to the file instead of stdout */
+  file?: string
+}
+
+/**
+ * Loads `i18n-scanner.config.json`, the closest one from the working directory
+ * when no path is given, relative paths of the config are resolved from its directory
+ */
+export declare function loadConfig(configPath?: string | undefined | null): ScanConfig
@@ -559,3 +559,8 @@ if (!nativeBinding) {
 module.exports = nativeBinding
 module.exports.I18nType = nativeBinding.I18nType
 module.exports.scan = nativeBinding.scan
+module.exports.scanDetailed = nativeBinding.scanDetailed
+module.exports.scanNested = nativeBinding.scanNested
+module.exports.findUnusedKeys = nativeBinding.findUnusedKeys
+module.exports.findMissingKeys = nativeBinding.findMissingKeys
+module.exports.writeResources = nativeBinding.writeResources
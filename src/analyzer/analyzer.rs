@@ -1,127 +1,239 @@
+use crate::analyzer::i18n_packages::global_t_names;
 use crate::analyzer::resolver::create_resolver;
 use crate::analyzer::walker::Walker;
-use crate::node::node::Node;
+use crate::error::ScanError;
+use crate::module_cache::ModuleCache;
+use crate::node::node::{Node, NodePath};
 use crate::node::node_store::NodeStore;
-use oxc_allocator::Allocator;
 use oxc_ast_visit::walk;
-use oxc_parser::{ParseOptions, Parser};
 use oxc_resolver::Resolver;
-use oxc_semantic::SemanticBuilder;
+use rayon::prelude::*;
 use regex::Regex;
-use std::fs;
-use std::rc::Rc;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
 
 pub struct Analyzer {
   pub node_store: NodeStore,
-  allocator: Allocator,
-  pub resolver: Rc<Resolver>,
+  pub module_cache: ModuleCache,
+  pub resolver: Arc<Resolver>,
   script_tester: Regex,
-  externals: Rc<Vec<Regex>>,
+  externals: Arc<Vec<Regex>>,
 }
 
 impl Analyzer {
-  pub fn new(all_nodes: NodeStore, tsconfig_path: String, externals: Vec<String>) -> Self {
-    Self {
+  pub fn new(
+    all_nodes: NodeStore,
+    module_cache: ModuleCache,
+    tsconfig_path: String,
+    externals: Vec<String>,
+  ) -> Result<Self, ScanError> {
+    let externals = externals
+      .iter()
+      .map(|pkg_name| {
+        Regex::new(&format!(r"^{}((!?\/).*)?$", pkg_name)).map_err(|err| {
+          ScanError::InvalidExternal {
+            pattern: pkg_name.clone(),
+            message: err.to_string(),
+          }
+        })
+      })
+      .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Self {
       node_store: all_nodes,
-      externals: Rc::new(
-        externals
-          .iter()
-          .map(|pkg_name| Regex::new(&format!(r"^{}((!?\/).*)?$", pkg_name)).unwrap())
-          .collect(),
-      ),
-      allocator: Allocator::default(),
-      resolver: Rc::new(create_resolver(tsconfig_path)),
-      script_tester: Regex::new(r"^.+\.(ts|tsx|js|jsx)$").unwrap(),
-    }
+      module_cache,
+      externals: Arc::new(externals),
+      resolver: Arc::new(create_resolver(tsconfig_path)),
+      script_tester: Regex::new(r"^.+\.(ts|tsx|js|jsx|mjs|cjs|mts|cts|vue|svelte|astro)$").unwrap(),
+    })
   }
 
-  pub fn analyze(
-    &mut self,
-    file_path: String,
-    imports_path: Option<Rc<String>>,
-  ) -> Option<Rc<Node>> {
-    if let Some(existing_node) = self.node_store.get_node(&file_path) {
-      if let Some(path) = imports_path {
-        existing_node.insert_imports(path.clone());
+  /// Whether the file is a source file the analyzer reads
+  pub fn is_script_file(&self, file_path: &str) -> bool {
+    self.script_tester.is_match(file_path)
+  }
+
+  /// Walks the import graph from the entries wave by wave,
+  /// files of the same wave are parsed and walked in parallel
+  pub fn analyze(&mut self, entry_paths: Vec<String>) -> &mut Self {
+    let mut pending: Vec<PendingImport> = entry_paths
+      .into_iter()
+      .map(|path| PendingImport {
+        importer: None,
+        path,
+      })
+      .collect();
+
+    let mut walked: Vec<Arc<Node>> = vec![];
+    // The i18n exports of the walked nodes when each node was last walked
+    let mut walked_with: HashMap<NodePath, Arc<I18nExports>> = HashMap::new();
+
+    loop {
+      while !pending.is_empty() {
+        let wave = self.prepare_wave(&pending);
+        self.link_imports(&pending);
+
+        let i18n_nodes = NodeStore::new(self.node_store.get_i18n_exported_nodes());
+        let exports = Arc::new(Self::i18n_exports(&walked));
+        for node in &wave {
+          walked_with.insert(node.file_path.clone(), exports.clone());
+        }
+        pending = wave
+          .par_iter()
+          .flat_map_iter(|node| self.walk_node(node, &i18n_nodes))
+          .collect();
+        walked.extend(wave);
       }
-      return Some(existing_node);
-    }
-    if !self.script_tester.is_match(&file_path) {
-      return None;
+
+      // A node could be walked before the nodes it imports have exported their i18n members,
+      // walk the ones whose imported i18n members changed again so their own exports are
+      // resolved as i18n members
+      let exports = Arc::new(Self::i18n_exports(&walked));
+      let stale = Self::mark_i18n_importers(&walked, &walked_with, &exports);
+      if stale.is_empty() {
+        break;
+      }
+      for node in &stale {
+        walked_with.insert(node.file_path.clone(), exports.clone());
+      }
+
+      let i18n_nodes = NodeStore::new(self.node_store.get_i18n_exported_nodes());
+      pending = stale
+        .par_iter()
+        .filter(|node| !node.get_exporting_members().is_empty())
+        .flat_map_iter(|node| self.walk_node(node, &i18n_nodes))
+        .collect();
     }
 
-    let file_path_ref = Rc::new(file_path);
-    let node = Rc::new(Node::new(file_path_ref.clone(), self.node_store.clone()));
-    let i18n_nodes = NodeStore::new(self.node_store.get_i18n_exported_nodes());
+    self
+  }
+
+  /// The i18n member names exported by every walked node
+  fn i18n_exports(walked: &[Arc<Node>]) -> I18nExports {
+    walked
+      .iter()
+      .filter(|node| node.has_exported_i18n_methods())
+      .map(|node| {
+        let members = node.get_exporting_i18n_members().into_keys().collect();
+        (node.file_path.clone(), members)
+      })
+      .collect()
+  }
 
-    if let Some(path) = imports_path {
-      node.insert_imports(path.clone());
+  /// Creates the nodes of files that are not analyzed yet
+  fn prepare_wave(&self, pending: &[PendingImport]) -> Vec<Arc<Node>> {
+    let mut paths: Vec<&String> = pending
+      .iter()
+      .map(|import| &import.path)
+      .filter(|path| self.node_store.get_node(path).is_none() && self.script_tester.is_match(path))
+      .collect();
+    paths.sort();
+    paths.dedup();
+
+    let loaded: Vec<&String> = paths
+      .into_par_iter()
+      .filter(|path| self.module_cache.get(path).is_some())
+      .collect();
+
+    loaded
+      .into_iter()
+      .map(|path| {
+        let file_path_ref = Arc::new(path.clone());
+        let node = Arc::new(Node::new(file_path_ref.clone(), self.node_store.clone()));
+        self.node_store.insert_node(file_path_ref, node.clone());
+        node
+      })
+      .collect()
+  }
+
+  fn link_imports(&self, pending: &[PendingImport]) {
+    for import in pending {
+      let Some((importer, source)) = &import.importer else {
+        continue;
+      };
+      let Some((path, node)) = self.node_store.get_path_and_node(&import.path) else {
+        continue;
+      };
+
+      node.insert_imports(importer.file_path.clone());
+      importer.insert_importing(source.clone(), path);
     }
+  }
 
-    let source_text = match fs::read_to_string(file_path_ref.as_ref()) {
-      Ok(source) => source,
-      Err(err) => {
-        log::warn!(
-          "[i18n-scanner-rs] Unable to read file at {}: {err}",
-          file_path_ref
-        );
-        return None;
-      }
+  fn walk_node(&self, node: &Arc<Node>, i18n_nodes: &NodeStore) -> Vec<PendingImport> {
+    let Some(module) = self.module_cache.get(node.file_path.as_str()) else {
+      return vec![];
     };
 
-    self.node_store.insert_node(file_path_ref, node.clone());
-
-    let parser =
-      Parser::new(&self.allocator, &source_text, node.source_type).with_options(ParseOptions {
-        // Keep scanning on files that have top-level returns in generated outputs.
-        allow_return_outside_function: true,
-        ..ParseOptions::default()
-      });
-    let parser_return = parser.parse();
-    if parser_return.panicked {
-      log::warn!(
-        "[i18n-scanner-rs] Parse panicked for file: {}",
-        node.file_path
-      );
-    }
-    if !parser_return.errors.is_empty() {
-      // Surface parse diagnostics together with file path so syntax compatibility issues are easier to locate.
-      log::debug!(
-        "[i18n-scanner-rs] Parse diagnostics for {}: {} error(s)",
-        node.file_path,
-        parser_return.errors.len()
-      );
-      for error in &parser_return.errors {
-        log::debug!("[i18n-scanner-rs] {error}");
-      }
-    }
-    let program = parser_return.program;
-    let semantic = SemanticBuilder::new().build(&program);
+    let program = module.program();
 
     let mut walker = Walker::new(
-      Rc::clone(&self.resolver),
+      Arc::clone(&self.resolver),
       node.clone(),
-      i18n_nodes,
-      &semantic.semantic,
+      i18n_nodes.clone(),
+      module.semantic(),
       self.externals.clone(),
+      self.module_cache.clone(),
     );
 
-    walk::walk_program(&mut walker, &program);
-
-    for (source, path) in walker.get_importing_collection().iter() {
-      if let Some(new_node) = self.analyze(path.to_string(), Some(node.file_path.clone())) {
-        node.insert_importing(source.to_string(), new_node.file_path.clone());
-
-        if new_node.has_exported_i18n_methods() {
-          node.mark_has_i18n_source_imported();
-        }
-      }
+    walk::walk_program(&mut walker, program);
+    // A component calling the translator of its templates uses i18n without importing it
+    let uses_global_t = global_t_names(&node.file_path)
+      .iter()
+      .any(|name| !walker.walk_utils.global_t_calls(name).is_empty());
+    if uses_global_t {
+      node.mark_has_i18n_source_imported();
     }
 
-    Some(node)
+    walker
+      .get_importing_collection()
+      .into_iter()
+      .map(|(source, path)| PendingImport {
+        importer: Some((node.clone(), source)),
+        path,
+      })
+      .collect()
+  }
+
+  /// Flags every walked node importing another walked node that exports i18n members,
+  /// returns the nodes that were not flagged before and the ones whose imported i18n members
+  /// changed since they were walked.
+  /// Imports of seeded packages are already flagged by the walker with their specifiers
+  fn mark_i18n_importers(
+    walked: &[Arc<Node>],
+    walked_with: &HashMap<NodePath, Arc<I18nExports>>,
+    exports: &I18nExports,
+  ) -> Vec<Arc<Node>> {
+    let mut marked: Vec<Arc<Node>> = walked
+      .iter()
+      .filter(|node| {
+        let importing = node.get_importing();
+        let seen = walked_with.get(&node.file_path);
+        let is_changed = importing
+          .values()
+          .any(|path| exports.get(path) != seen.and_then(|seen| seen.get(path)));
+        let is_marked = importing.values().any(|path| exports.contains_key(path))
+          && node.mark_has_i18n_source_imported();
+
+        is_marked || is_changed
+      })
+      .cloned()
+      .collect();
+    marked.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+
+    marked
   }
 }
 
+/// { file path: names of its exported i18n members }
+type I18nExports = HashMap<NodePath, BTreeSet<String>>;
+
+struct PendingImport {
+  // (importer node, import source)
+  importer: Option<(Arc<Node>, String)>,
+  path: String,
+}
+
 #[cfg(test)]
 mod tests {
   use crate::analyzer::test_utils::{
@@ -132,14 +244,14 @@ mod tests {
   fn make_seed() {
     let (_, node_store) = analyze("index.tsx".into(), None);
 
-    assert_eq!(node_store.get_i18n_exported_nodes().len(), 5);
+    assert_eq!(node_store.get_i18n_exported_nodes().len(), 6);
   }
 
   #[test]
   fn node_include_i18n_import() {
     let (_, node_store) = analyze("index.tsx".into(), None);
 
-    assert_eq!(node_store.get_all_i18n_nodes().len(), 29);
+    assert_eq!(node_store.get_all_i18n_nodes().len(), 31);
   }
 
   #[test]
@@ -148,7 +260,7 @@ mod tests {
 
     let (_, node_store) = analyze("index.tsx".into(), Some(pkgs));
 
-    assert_eq!(node_store.get_i18n_exported_nodes().len(), 5);
+    assert_eq!(node_store.get_i18n_exported_nodes().len(), 6);
   }
 
   #[test]
@@ -1,10 +1,10 @@
 use crate::analyzer::analyzer::Analyzer;
-use crate::node::i18n_types::{I18nMember, I18nType};
+use crate::node::i18n_types::{I18nMember, I18nType, KeyMethod, TArguments};
 use crate::node::node::Node;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::mem::{self, Discriminant};
 use std::path::Path;
-use std::rc::Rc;
+use std::sync::Arc;
 
 ///
 /// We assume those packages includes the below methods exposed
@@ -14,8 +14,20 @@ use std::rc::Rc;
 /// - Translation: the i18n component
 /// - withTranslation: the i18n HOC function
 ///
-/// and we don't handle other exposed methods from this package
-pub static PRESET_I18N_PACKAGES: &[&str] = &["i18next", "react-i18next"];
+/// and we don't handle other exposed methods from this package,
+/// `vue-i18n` only exposes the `useI18n` hook, its members are only read from its own imports
+pub static PRESET_I18N_PACKAGES: &[(&str, &[(&str, I18nType)])] = &[
+  ("i18next", PRESET_I18N_MEMBERS),
+  ("react-i18next", PRESET_I18N_MEMBERS),
+  ("vue-i18n", VUE_I18N_MEMBERS),
+];
+
+/// The method of the i18n instance returning a `t` bound to a namespace and key prefix
+pub static FIXED_T_METHOD: &str = "getFixedT";
+
+/// Methods of the i18n instance reading keys: (name, key index, namespace index)
+pub static PRESET_KEY_METHODS: &[(&str, u32, Option<u32>)] =
+  &[("exists", 0, None), ("getResource", 2, Some(1))];
 
 pub static PRESET_I18N_MEMBERS: &[(&str, I18nType)] = &[
   // Special symbol import as namespace
@@ -27,13 +39,48 @@ pub static PRESET_I18N_MEMBERS: &[(&str, I18nType)] = &[
   ("i18n", I18nType::ObjectMemberT),
 ];
 
+pub static VUE_I18N_MEMBERS: &[(&str, I18nType)] = &[("useI18n", I18nType::Hook)];
+
+/// The translators vue-i18n gives every component without an import, called in the templates
+/// or on `this` in the Options API
+pub static VUE_I18N_GLOBALS: &[&str] = &["$t", "$tc"];
+
+/// The translators a file may call without declaring them, only `.vue` files have one
+pub fn global_t_names(file_path: &str) -> &'static [&'static str] {
+  if file_path.ends_with(".vue") {
+    VUE_I18N_GLOBALS
+  } else {
+    &[]
+  }
+}
+
 fn default_members() -> Vec<Member> {
-  PRESET_I18N_MEMBERS
+  members_of(PRESET_I18N_MEMBERS)
+}
+
+fn members_of(preset: &[(&str, I18nType)]) -> Vec<Member> {
+  preset
     .iter()
     .map(|(name, r#type)| Member {
       name: (*name).to_string(),
       r#type: r#type.clone(),
       ns: None,
+      key_prefix: None,
+      key_methods: None,
+      key_index: None,
+      ns_index: None,
+      options_index: None,
+    })
+    .collect()
+}
+
+pub fn preset_key_methods() -> Vec<KeyMethod> {
+  PRESET_KEY_METHODS
+    .iter()
+    .map(|(name, key_index, ns_index)| KeyMethod {
+      name: (*name).to_string(),
+      key_index: *key_index,
+      ns_index: *ns_index,
     })
     .collect()
 }
@@ -44,6 +91,15 @@ pub struct Member {
   pub name: String,
   pub r#type: I18nType,
   pub ns: Option<String>,
+  pub key_prefix: Option<String>,
+  /// Methods of an `ObjectMemberT` member reading keys, defaults to `exists` and `getResource`
+  pub key_methods: Option<Vec<KeyMethod>>,
+  /// Index of the key argument of a `TMethod` member, defaults to 0
+  pub key_index: Option<u32>,
+  /// Index of the namespace argument of a `TMethod` member, read from the options when omitted
+  pub ns_index: Option<u32>,
+  /// Index of the options argument of a `TMethod` member, defaults to the one after the key
+  pub options_index: Option<u32>,
 }
 #[derive(Clone)]
 #[napi(object)]
@@ -60,8 +116,8 @@ impl Analyzer {
   ) -> &mut Self {
     let i18n_packages = self.extend_i18n_packages(entry_path, extend_i18n_packages);
     for package in i18n_packages {
-      let file_path_ref = Rc::new(package.package_path);
-      let node = Rc::new(Node::new(file_path_ref.clone(), self.node_store.clone()));
+      let file_path_ref = Arc::new(package.package_path);
+      let node = Arc::new(Node::new(file_path_ref.clone(), self.node_store.clone()));
 
       for member in package.members {
         node.insert_exporting(
@@ -69,6 +125,13 @@ impl Analyzer {
           Some(I18nMember {
             r#type: member.r#type,
             ns: member.ns,
+            key_prefix: member.key_prefix,
+            key_methods: member.key_methods,
+            arguments: TArguments {
+              key: member.key_index.unwrap_or(0) as usize,
+              ns: member.ns_index.map(|index| index as usize),
+              options: member.options_index.map(|index| index as usize),
+            },
           }),
         );
       }
@@ -82,13 +145,13 @@ impl Analyzer {
   pub fn make_preset_i18n_packages(&self, basename: &Path) -> Vec<I18nPackage> {
     let mut methods: Vec<I18nPackage> = Vec::new();
 
-    for pkg_name in PRESET_I18N_PACKAGES {
+    for (pkg_name, members) in PRESET_I18N_PACKAGES {
       // resolve packages and confirm it's installed
       if let Ok(res) = self.resolver.resolve(basename, pkg_name) {
         if let Some(path_str) = res.path().to_str() {
           methods.push(I18nPackage {
             package_path: path_str.to_string(),
-            members: default_members(),
+            members: members_of(members),
           })
         }
       }
@@ -128,6 +191,11 @@ impl Analyzer {
                     name: (*name).to_string(),
                     r#type: preset_type.clone(),
                     ns: None,
+                    key_prefix: None,
+                    key_methods: None,
+                    key_index: None,
+                    ns_index: None,
+                    options_index: None,
                   });
                   registered_types.insert(preset_discriminant);
                 }
@@ -154,6 +222,7 @@ fn discriminant_of(member_type: &I18nType) -> Discriminant<I18nType> {
   mem::discriminant(member_type)
 }
 
+/// Whether a name is an i18next member of the type, for files whose i18n import is not followed
 pub fn is_preset_member_name(name: &str, member_type: &I18nType) -> bool {
   let discriminant = discriminant_of(member_type);
 
@@ -180,3 +249,16 @@ pub fn preset_member_type(name: &str) -> Option<I18nType> {
     .iter()
     .find_map(|(preset_name, preset_type)| (*preset_name == name).then(|| preset_type.clone()))
 }
+
+/// i18next exports its instance as default, so the default import of a package
+/// without a default export of its own is the `ObjectMemberT` member
+pub fn default_import_member(members: &HashMap<String, Option<I18nMember>>) -> Option<I18nMember> {
+  if members.contains_key("default") {
+    return None;
+  }
+
+  members.values().find_map(|member| match member {
+    Some(member) if matches!(member.r#type, I18nType::ObjectMemberT) => Some(member.clone()),
+    _ => None,
+  })
+}
@@ -32,9 +32,24 @@ pub fn create_resolver(tsconfig_path: String) -> Resolver {
   debug!("tsconfig: {:?}", tsconfig);
 
   let r = Resolver::new(ResolveOptions {
-    extensions: vec![".ts".into(), ".tsx".into(), ".js".into(), ".jsx".into()],
+    extensions: vec![
+      ".ts".into(),
+      ".tsx".into(),
+      ".js".into(),
+      ".jsx".into(),
+      ".mts".into(),
+      ".cts".into(),
+      ".mjs".into(),
+      ".cjs".into(),
+      // Component files, imported with their extension most of the time
+      ".vue".into(),
+      ".svelte".into(),
+      ".astro".into(),
+    ],
     // ESM
     condition_names: vec!["import".into(), "default".into(), "module".into()],
+    // Imports of `fs` or `node:path` fail as builtins rather than missing files
+    builtin_modules: true,
     cwd,
     tsconfig,
     ..ResolveOptions::default()
@@ -1,5 +1,6 @@
 use crate::analyzer::analyzer::Analyzer;
 use crate::analyzer::i18n_packages::{I18nPackage, Member};
+use crate::module_cache::ModuleCache;
 use crate::node::i18n_types::I18nType;
 use crate::node::node_store::NodeStore;
 use fs::canonicalize;
@@ -28,13 +29,19 @@ pub fn analyze_with_options(
 ) -> (Analyzer, NodeStore) {
   let node_store = NodeStore::default();
 
-  let mut analyzer = Analyzer::new(node_store.clone(), test_path("../tsconfig.json"), externals);
+  let mut analyzer = Analyzer::new(
+    node_store.clone(),
+    ModuleCache::default(),
+    test_path("../tsconfig.json"),
+    externals,
+  )
+  .unwrap();
 
   let source_path = test_path(entry.as_str());
 
   analyzer
     .seed(&source_path, extend_packages)
-    .analyze(source_path, None);
+    .analyze(vec![source_path]);
 
   (analyzer, node_store)
 }
@@ -46,6 +53,11 @@ pub fn make_extend_packages() -> Vec<I18nPackage> {
       r#type: I18nType::Hook,
       name: "useFeTranslation".to_string(),
       ns: Some("namespace_3".into()),
+      key_prefix: None,
+      key_index: None,
+      ns_index: None,
+      options_index: None,
+      key_methods: None,
     }],
   }]
 }
@@ -1,13 +1,13 @@
 use super::walker::Walker;
 use crate::analyzer::i18n_packages::preset_member_type;
-use crate::node::i18n_types::I18nMember;
+use crate::node::i18n_types::{I18nMember, TArguments};
 use oxc_ast::ast::{
-  ArrayPattern, BindingPattern, Declaration, ExportAllDeclaration, ExportDefaultDeclaration,
-  ExportNamedDeclaration, Expression, ImportDeclaration, ImportDeclarationSpecifier,
-  ImportExpression, ModuleExportName, ObjectPattern,
+  ArrayPattern, AssignmentExpression, AssignmentTarget, BindingPattern, CallExpression,
+  Declaration, ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, Expression,
+  ImportDeclaration, ImportDeclarationSpecifier, ImportExpression, ModuleExportName, ObjectPattern,
+  ObjectPropertyKind, StaticMemberExpression, VariableDeclarator,
 };
-use oxc_ast::AstKind;
-use oxc_ast_visit::Visit;
+use oxc_ast_visit::{walk, Visit};
 
 impl<'a> Visit<'a> for Walker<'a> {
   // import('xyz')
@@ -73,24 +73,7 @@ impl<'a> Visit<'a> for Walker<'a> {
 
             match &specifier.local {
               ModuleExportName::IdentifierReference(ident) => {
-                let resolved_member = ident.reference_id.get().and_then(|reference_id| {
-                  self
-                    .walk_utils
-                    .get_var_defined_node(reference_id)
-                    .and_then(|node| match node.kind() {
-                      AstKind::VariableDeclarator(decl) => self.resolve_i18n_export(&decl),
-                      _ => None,
-                    })
-                });
-
-                if resolved_member.is_none() {
-                  log::debug!(
-                    "[i18n-scanner-rs] unable to resolve exported member '{}' in file '{}'",
-                    exported_name,
-                    self.walk_utils.node.file_path.as_str()
-                  );
-                }
-
+                let resolved_member = self.resolve_exported_reference(&exported_name, ident);
                 (exported_name, resolved_member)
               }
               _ => (exported_name, None),
@@ -113,6 +96,9 @@ impl<'a> Visit<'a> for Walker<'a> {
             let member = preset_member_type(name.as_str()).map(|member_type| I18nMember {
               r#type: member_type,
               ns: None,
+              key_prefix: None,
+              key_methods: None,
+              arguments: TArguments::default(),
             });
 
             vec![(name, member)]
@@ -154,6 +140,102 @@ impl<'a> Visit<'a> for Walker<'a> {
     self.resolve_import(&it.source, vec!["*".into()]);
     self.append_reexport(&it.source);
   }
+
+  // const xyz = require('./xyz');
+  // const { x, y, z } = require('./xyz');
+  fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+    let source = it
+      .init
+      .as_ref()
+      .and_then(|init| self.walk_utils.read_require_source(init));
+    let Some(source) = source else {
+      walk::walk_variable_declarator(self, it);
+      return;
+    };
+
+    let specifiers = match &it.id {
+      BindingPattern::ObjectPattern(obj) => obj
+        .properties
+        .iter()
+        .filter_map(|prop| prop.key.static_name().map(|name| name.to_string()))
+        .chain(obj.rest.as_ref().map(|_| "*".to_string()))
+        .collect(),
+      // The whole module is bound like a namespace import
+      _ => vec!["*".into()],
+    };
+    self.resolve_import(source, specifiers);
+  }
+
+  // require('./xyz')
+  fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+    if let Some(source) = self.walk_utils.read_require_call(it) {
+      self.resolve_import(source, vec![]);
+    }
+    walk::walk_call_expression(self, it);
+  }
+
+  // module.exports = { a, b };
+  // exports.a = a;
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+    if let AssignmentTarget::StaticMemberExpression(target) = &it.left {
+      let exports = if is_module_exports(target) {
+        match &it.right {
+          // module.exports = { a, b: c };
+          Expression::ObjectExpression(obj) => obj
+            .properties
+            .iter()
+            .filter_map(|prop| match prop {
+              ObjectPropertyKind::ObjectProperty(prop) => prop.key.static_name().map(|name| {
+                let name = name.to_string();
+                let member = match &prop.value {
+                  Expression::Identifier(ident) => self.resolve_exported_reference(&name, ident),
+                  _ => None,
+                };
+                (name, member)
+              }),
+              ObjectPropertyKind::SpreadProperty(_) => None,
+            })
+            .collect(),
+          // module.exports = a;
+          Expression::Identifier(ident) => {
+            vec![(
+              "default".into(),
+              self.resolve_exported_reference("default", ident),
+            )]
+          }
+          _ => vec![("default".into(), None)],
+        }
+      } else if is_exports_object(&target.object) {
+        // exports.a = a; module.exports.a = a;
+        let name = target.property.name.to_string();
+        let member = match &it.right {
+          Expression::Identifier(ident) => self.resolve_exported_reference(&name, ident),
+          _ => None,
+        };
+        vec![(name, member)]
+      } else {
+        vec![]
+      };
+      self.append_exports(exports);
+    }
+
+    walk::walk_assignment_expression(self, it);
+  }
+}
+
+/// module.exports
+fn is_module_exports(member: &StaticMemberExpression) -> bool {
+  matches!(&member.object, Expression::Identifier(object) if object.name == "module")
+    && member.property.name == "exports"
+}
+
+/// `exports` or `module.exports`
+fn is_exports_object(expr: &Expression) -> bool {
+  match expr {
+    Expression::Identifier(ident) => ident.name == "exports",
+    Expression::StaticMemberExpression(member) => is_module_exports(member),
+    _ => false,
+  }
 }
 
 fn collect_deconstructed_array_export(arr: &ArrayPattern) -> Vec<(String, Option<I18nMember>)> {
@@ -1,23 +1,28 @@
-use crate::analyzer::i18n_packages::{is_preset_member_name, preset_member_type};
-use crate::node::i18n_types::{I18nMember, I18nType};
+use crate::analyzer::i18n_packages::{
+  default_import_member, is_preset_member_name, preset_member_type, FIXED_T_METHOD,
+};
+use crate::diagnostics::{Diagnostic, DiagnosticSeverity};
+use crate::module_cache::ModuleCache;
+use crate::node::i18n_types::{I18nMember, I18nType, TArguments};
 use crate::node::node::Node;
 use crate::node::node_store::NodeStore;
 use crate::walk_utils::WalkerUtils;
 use log::debug;
 use oxc_ast::ast::{
-  CallExpression, Expression, FunctionBody, Statement, StringLiteral, VariableDeclarator,
+  CallExpression, Expression, FunctionBody, IdentifierReference, Statement, StringLiteral,
+  VariableDeclarator,
 };
 use oxc_ast::AstKind;
-use oxc_resolver::Resolver;
-use oxc_semantic::Semantic;
+use oxc_resolver::{ResolveError, Resolver};
+use oxc_semantic::{AstNode, Semantic};
 use regex::Regex;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub struct Walker<'a> {
-  resolver: Rc<Resolver>,
-  node: Rc<Node>,
-  externals: Rc<Vec<Regex>>,
+  resolver: Arc<Resolver>,
+  node: Arc<Node>,
+  externals: Arc<Vec<Regex>>,
   // reexport all members
   reexport_all_importing: Vec<String>,
   // { exports: [], file_paths: '' }[]
@@ -30,11 +35,12 @@ pub struct Walker<'a> {
 
 impl<'a> Walker<'a> {
   pub fn new(
-    resolver: Rc<Resolver>,
-    node: Rc<Node>,
+    resolver: Arc<Resolver>,
+    node: Arc<Node>,
     i18n_methods: NodeStore,
     semantic: &'a Semantic<'a>,
-    externals: Rc<Vec<Regex>>,
+    externals: Arc<Vec<Regex>>,
+    module_cache: ModuleCache,
   ) -> Self {
     Self {
       externals,
@@ -44,7 +50,7 @@ impl<'a> Walker<'a> {
       reexport_all_importing: Vec::new(),
       i18n_methods,
       importing_collection: HashMap::new(),
-      walk_utils: WalkerUtils::new(semantic, node),
+      walk_utils: WalkerUtils::new(semantic, node, module_cache),
     }
   }
 
@@ -64,8 +70,12 @@ impl<'a> Walker<'a> {
 
       if specifiers.iter().any(|specifier| {
         // is matched i18n methods or
-        // namespace import, no matter is reexport all or not
-        importing_node_members.contains_key(specifier) || specifier == "*"
+        // namespace import, no matter is reexport all or not or
+        // the default exported i18n instance
+        importing_node_members.contains_key(specifier)
+          || specifier == "*"
+          || (specifier == "default"
+            && default_import_member(&node.get_exporting_members()).is_some())
       }) {
         self.node.mark_has_i18n_source_imported();
       }
@@ -109,11 +119,27 @@ impl<'a> Walker<'a> {
           debug!("[i18n-scanner-rs] failed to format path: {}", source.value)
         }
       }
+      // Node builtins have no source to follow
+      Err(ResolveError::Builtin { .. }) => {}
       Err(err) => {
         debug!(
           "[i18n-scanner-rs] failed to resolve: {} in {} ({})",
           source.value, self.node.file_path, err
         );
+        if !is_external {
+          let span = source.span;
+          self
+            .walk_utils
+            .module_cache
+            .diagnostics
+            .push(Diagnostic::at(
+              &self.node.file_path,
+              self.semantic.source_text(),
+              (span.start, span.end),
+              format!("Unable to resolve '{}': {err}", source.value),
+              DiagnosticSeverity::Warning,
+            ));
+        }
       }
     }
   }
@@ -201,6 +227,9 @@ impl<'a> Walker<'a> {
       preset_member_type(name).map(|member_type| I18nMember {
         r#type: member_type,
         ns: None,
+        key_prefix: None,
+        key_methods: None,
+        arguments: TArguments::default(),
       })
     });
 
@@ -256,6 +285,9 @@ impl<'a> Walker<'a> {
           return Some(I18nMember {
             r#type: crate::node::i18n_types::I18nType::Hook,
             ns: None,
+            key_prefix: None,
+            key_methods: None,
+            arguments: TArguments::default(),
           });
         }
 
@@ -277,12 +309,41 @@ impl<'a> Walker<'a> {
         }
         resolve_return_fn(&body.statements[0])
       }
+      // const t = i18n.getFixedT(null, 'ns', 'keyPrefix');
+      Expression::CallExpression(call) => self.resolve_fixed_t(call),
       _ => None,
     };
 
     member.or_else(|| fallback_member.clone())
   }
 
+  /// The i18n member of a local binding exported as `exported_name`
+  pub fn resolve_exported_reference(
+    &self,
+    exported_name: &str,
+    ident: &IdentifierReference,
+  ) -> Option<I18nMember> {
+    let resolved_member = ident.reference_id.get().and_then(|reference_id| {
+      self
+        .walk_utils
+        .get_var_defined_node(reference_id)
+        .and_then(|node| match node.kind() {
+          AstKind::VariableDeclarator(decl) => self.resolve_i18n_export(decl),
+          _ => None,
+        })
+    });
+
+    if resolved_member.is_none() {
+      log::debug!(
+        "[i18n-scanner-rs] unable to resolve exported member '{}' in file '{}'",
+        exported_name,
+        self.walk_utils.node.file_path.as_str()
+      );
+    }
+
+    resolved_member
+  }
+
   pub fn resolve_expression_fn(&self, state: &Statement) -> Option<I18nMember> {
     let Statement::ExpressionStatement(exp) = state else {
       return None;
@@ -298,6 +359,50 @@ impl<'a> Walker<'a> {
       return None;
     };
 
+    self.resolve_imported_member(id).map(|member| {
+      let ns = self.walk_utils.read_hook_namespace_argument(call_exp);
+      let key_prefix = match member.r#type {
+        I18nType::Hook => self.walk_utils.read_hook_key_prefix(call_exp),
+        _ => None,
+      };
+      I18nMember {
+        r#type: member.r#type.clone(),
+        ns,
+        key_prefix,
+        key_methods: member.key_methods.clone(),
+        arguments: member.arguments.clone(),
+      }
+    })
+  }
+
+  /// i18n.getFixedT(null, 'ns', 'keyPrefix') on an imported i18n instance
+  pub fn resolve_fixed_t(&self, call_exp: &CallExpression) -> Option<I18nMember> {
+    let Expression::StaticMemberExpression(member_exp) = &call_exp.callee else {
+      return None;
+    };
+    if member_exp.property.name != FIXED_T_METHOD {
+      return None;
+    }
+    let Expression::Identifier(id) = &member_exp.object else {
+      return None;
+    };
+
+    self
+      .resolve_imported_member(id)
+      .filter(|member| matches!(member.r#type, I18nType::ObjectMemberT))
+      .map(|member| {
+        let (ns, key_prefix) = self.walk_utils.read_fixed_t_arguments(call_exp);
+        I18nMember {
+          r#type: I18nType::FixedT,
+          ns: ns.or(member.ns),
+          key_prefix,
+          key_methods: None,
+          arguments: TArguments::default(),
+        }
+      })
+  }
+
+  fn resolve_imported_member(&self, id: &IdentifierReference) -> Option<I18nMember> {
     let Some(reference_id) = id.reference_id.get() else {
       // Some invalid/unsupported syntax paths may not bind a reference id.
       log::debug!(
@@ -318,34 +423,41 @@ impl<'a> Walker<'a> {
     };
     let node = self.semantic.symbol_declaration(symbol_id);
 
+    let import_source = |ast_node: &AstNode| match ast_node.kind() {
+      AstKind::ImportDeclaration(decl) => Some(decl.source.value.to_string()),
+      _ => None,
+    };
     let spec = match node.kind() {
-      AstKind::ImportSpecifier(spec) => Some((
-        spec.imported.name().to_string(),
-        self.semantic.nodes().parent_node(node.id()),
-      )),
+      AstKind::ImportSpecifier(spec) => Some(spec.imported.name().to_string())
+        .zip(import_source(self.semantic.nodes().parent_node(node.id()))),
+      AstKind::ImportDefaultSpecifier(_) => {
+        Some("default".to_string()).zip(import_source(self.semantic.nodes().parent_node(node.id())))
+      }
+      // const { useTranslation } = require('react-i18next');
+      AstKind::VariableDeclarator(var) => self.walk_utils.read_required_name(var, symbol_id).zip(
+        var
+          .init
+          .as_ref()
+          .and_then(|init| self.walk_utils.read_require_source(init))
+          .map(|source| source.value.to_string()),
+      ),
       _ => None,
     };
 
-    let member = spec
-      .and_then(|(spec, ast_node)| match ast_node.kind() {
-        AstKind::ImportDeclaration(decl) => self
+    spec
+      .and_then(|(spec, source)| {
+        self
           .node
-          .get_importing_node(&decl.source.value.to_string())
-          .and_then(|node| Some((spec, node))),
-        _ => None,
+          .get_importing_node(&source)
+          .and_then(|node| Some((spec, node)))
       })
       .and_then(|(spec, node)| {
-        let members = node.get_exporting_i18n_members();
-        if let Some(member) = members.get(&spec) {
-          let ns = self.walk_utils.read_hook_namespace_argument(&call_exp);
-          return Some(I18nMember {
-            r#type: member.r#type.clone(),
-            ns,
-          });
+        let members = node.get_exporting_members();
+        match members.get(&spec) {
+          Some(member) => member.clone(),
+          None if spec == "default" => default_import_member(&members),
+          None => None,
         }
-        None
-      });
-
-    member
+      })
   }
 }
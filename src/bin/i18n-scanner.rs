@@ -0,0 +1,4 @@
+fn main() {
+  let code = i18n_scanner_rs::cli::run(std::env::args().skip(1).collect());
+  std::process::exit(code);
+}
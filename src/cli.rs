@@ -0,0 +1,477 @@
+use crate::collector::key_detail::KeyDetail;
+use crate::config::{find_config, load_config, read_payload, OutputOptions};
+use crate::diagnostics::Diagnostic;
+use crate::entries::is_glob;
+use crate::error::ScanError;
+use crate::Payload;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{env, fs};
+
+const USAGE: &str = "Usage: i18n-scanner [options] [entry...]
+
+Scans the entries and their imports and prints the collected keys as JSON
+
+Options:
+  -c, --config <file>       Config file, defaults to the closest i18n-scanner.config.json from the working directory
+  -p, --tsconfig <file>     tsconfig.json used to resolve the imports
+  -e, --entry <file>        Entry file or glob pattern like `src/pages/**/*.tsx`, may be repeated
+  -i, --include <glob>      Source files scanned as entries whether they are imported or not, may be repeated
+      --exclude <glob>      Files left out of the matched entries, may be repeated
+  -x, --external <package>  Package whose imports are not followed, may be repeated
+      --packages <file>     JSON file listing extra i18n packages, same as `extendI18NPackages`
+  -j, --threads <count>     Number of threads, defaults to the number of CPUs
+  -f, --format <format>     `keys`, `detailed` or `nested`, defaults to `keys`
+  -o, --output <file>       Writes the JSON to the file instead of stdout
+      --fail-on-parse-error Fails when a file cannot be read or parsed instead of skipping its keys
+  -h, --help                Prints this help";
+
+/// Shape of the printed result, same as `scan`, `scanDetailed` and `scanNested`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum OutputFormat {
+  #[default]
+  Keys,
+  Detailed,
+  Nested,
+}
+
+impl OutputFormat {
+  pub fn parse(format: &str) -> Option<Self> {
+    match format {
+      "keys" => Some(OutputFormat::Keys),
+      "detailed" => Some(OutputFormat::Detailed),
+      "nested" => Some(OutputFormat::Nested),
+      _ => None,
+    }
+  }
+}
+
+/// The command line, the flags override the fields of the config file
+#[derive(Debug, Default)]
+pub struct CliOptions {
+  pub config: Option<String>,
+  pub tsconfig: Option<String>,
+  pub entries: Vec<String>,
+  pub include: Vec<String>,
+  pub exclude: Vec<String>,
+  pub externals: Vec<String>,
+  pub packages: Option<String>,
+  pub threads: Option<u32>,
+  pub format: Option<OutputFormat>,
+  pub output: Option<String>,
+  pub fail_on_parse_error: bool,
+  pub help: bool,
+}
+
+/// What to scan and where to print it
+pub struct CliScan {
+  pub payload: Payload,
+  pub format: OutputFormat,
+  pub output: Option<String>,
+}
+
+/// Runs the command line and returns the exit code, 2 for a bad usage and 1 when the scan fails
+pub fn run(args: Vec<String>) -> i32 {
+  let options = match parse_args(args) {
+    Ok(options) => options,
+    Err(err) => {
+      eprintln!("i18n-scanner: {err}\n\n{USAGE}");
+      return 2;
+    }
+  };
+  if options.help {
+    println!("{USAGE}");
+    return 0;
+  }
+
+  let result = read_scan(&options)
+    .map_err(|err| (1, err))
+    .and_then(|scan| {
+      let (result, diagnostics) = scan_json(scan.payload, &scan.format)
+        .map_err(|err| (scan_exit_code(&err), err.to_string()))?;
+      for diagnostic in &diagnostics {
+        let severity = diagnostic.severity.as_str().to_lowercase();
+        eprintln!("i18n-scanner: {severity}: {diagnostic}");
+      }
+      let json = serde_json::to_string_pretty(&result).map_err(|err| (1, err.to_string()))?;
+      match &scan.output {
+        Some(output) => fs::write(output, json + "\n")
+          .map_err(|err| (1, format!("Unable to write the result to {output}: {err}"))),
+        None => {
+          println!("{json}");
+          Ok(())
+        }
+      }
+    });
+
+  match result {
+    Ok(()) => 0,
+    Err((code, err)) => {
+      eprintln!("i18n-scanner: {err}");
+      code
+    }
+  }
+}
+
+/// No script matching the entries is a bad usage like an unknown flag, the other errors fail the scan
+fn scan_exit_code(err: &ScanError) -> i32 {
+  match err {
+    ScanError::EmptyEntries => 2,
+    _ => 1,
+  }
+}
+
+pub fn parse_args(args: Vec<String>) -> Result<CliOptions, String> {
+  let mut options = CliOptions::default();
+  let mut args = args.into_iter();
+
+  while let Some(arg) = args.next() {
+    // `--flag=value` is the same as `--flag value`
+    let (flag, inline_value) = match arg.split_once('=') {
+      Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+      _ => (arg.clone(), None),
+    };
+    let mut value = || {
+      inline_value
+        .clone()
+        .or_else(|| args.next())
+        .ok_or_else(|| format!("missing value for {flag}"))
+    };
+
+    match flag.as_str() {
+      "-h" | "--help" => options.help = true,
+      "-c" | "--config" => options.config = Some(value()?),
+      "-p" | "--tsconfig" => options.tsconfig = Some(value()?),
+      "-e" | "--entry" => options.entries.push(value()?),
+      "-i" | "--include" => options.include.push(value()?),
+      "--exclude" => options.exclude.push(value()?),
+      "-x" | "--external" => options.externals.push(value()?),
+      "--packages" => options.packages = Some(value()?),
+      "-j" | "--threads" => {
+        let threads = value()?;
+        options.threads = Some(
+          threads
+            .parse()
+            .map_err(|_| format!("invalid thread count '{threads}'"))?,
+        );
+      }
+      "-f" | "--format" => {
+        let format = value()?;
+        options.format =
+          Some(OutputFormat::parse(&format).ok_or_else(|| format!("unknown format '{format}'"))?);
+      }
+      "-o" | "--output" => options.output = Some(value()?),
+      "--fail-on-parse-error" => options.fail_on_parse_error = true,
+      _ if flag.starts_with('-') && flag.len() > 1 => {
+        return Err(format!("unknown option {flag}"));
+      }
+      _ => options.entries.push(arg),
+    }
+  }
+
+  Ok(options)
+}
+
+/// The config file with the flags applied over it, the scan runs on the flags alone
+/// when no config file is passed or found
+pub fn read_scan(options: &CliOptions) -> Result<CliScan, String> {
+  let config_path = match &options.config {
+    Some(config) => Some(config.clone()),
+    None => env::current_dir()
+      .ok()
+      .and_then(|cwd| find_config(&cwd))
+      .map(|config_path| config_path.display().to_string()),
+  };
+  let config = config_path
+    .map(|config_path| load_config(Some(&config_path)))
+    .transpose()
+    .map_err(|err| err.to_string())?;
+
+  let (mut payload, output) = match config {
+    Some(config) => (config.payload, config.output),
+    None => (
+      read_payload(&json!({})).map_err(|err| err.to_string())?,
+      None,
+    ),
+  };
+  let output = output.unwrap_or(OutputOptions {
+    format: None,
+    file: None,
+  });
+
+  if let Some(tsconfig) = &options.tsconfig {
+    payload.tsconfig_path = tsconfig.clone();
+  }
+  if !options.entries.is_empty() {
+    payload.entry_paths = options.entries.clone();
+  }
+  if !options.include.is_empty() {
+    payload
+      .include
+      .get_or_insert_default()
+      .extend(options.include.iter().cloned());
+  }
+  if !options.exclude.is_empty() {
+    payload
+      .exclude
+      .get_or_insert_default()
+      .extend(options.exclude.iter().cloned());
+  }
+  payload.externals.extend(options.externals.iter().cloned());
+  if let Some(packages) = &options.packages {
+    let packages = json!({ "extendI18NPackages": read_json(packages)? });
+    payload.extend_i18n_packages = read_payload(&packages)
+      .map_err(|err| format!("{}: {err}", options.packages.as_deref().unwrap_or_default()))?
+      .extend_i18n_packages;
+  }
+  if options.threads.is_some() {
+    payload.threads = options.threads;
+  }
+  if options.fail_on_parse_error {
+    payload.fail_on_parse_error = Some(true);
+  }
+
+  if let Some(missing) = payload
+    .entry_paths
+    .iter()
+    .find(|entry| !is_glob(entry) && !Path::new(entry).is_file())
+  {
+    return Err(format!("entry {missing} does not exist"));
+  }
+  // The analyzer keys the files by their absolute path, glob patterns are matched by the scan
+  payload.entry_paths = payload
+    .entry_paths
+    .iter()
+    .map(|entry| {
+      if is_glob(entry) {
+        return Ok(entry.clone());
+      }
+      fs::canonicalize(entry)
+        .map(|path| path.display().to_string())
+        .map_err(|err| format!("Unable to read entry {entry}: {err}"))
+    })
+    .collect::<Result<_, _>>()?;
+  Ok(CliScan {
+    payload,
+    format: options
+      .format
+      .clone()
+      .or_else(|| output.format.as_deref().and_then(OutputFormat::parse))
+      .unwrap_or_default(),
+    output: options.output.clone().or(output.file),
+  })
+}
+
+/// Scans and returns the result the same way the JS API does, with the diagnostics of the scan
+pub fn scan_json(
+  payload: Payload,
+  format: &OutputFormat,
+) -> Result<(Value, Vec<Diagnostic>), ScanError> {
+  let collector = crate::collect(payload)?;
+  let diagnostics = collector.get_diagnostics();
+
+  let result = match format {
+    OutputFormat::Keys => sorted_object(
+      collector
+        .i18n_namespaces
+        .iter()
+        .map(|(namespace, keys)| (namespace.clone(), json!(keys))),
+    ),
+    OutputFormat::Detailed => json!({
+      "namespaces": sorted_object(collector.get_key_details().into_iter().map(
+        |(namespace, details)| {
+          let details = details.iter().map(key_detail_json).collect::<Vec<_>>();
+          (namespace, Value::Array(details))
+        }
+      )),
+      "diagnostics": diagnostics.iter().map(diagnostic_json).collect::<Vec<_>>(),
+    }),
+    OutputFormat::Nested => sorted_object(collector.get_key_trees().into_iter()),
+  };
+
+  Ok((result, diagnostics))
+}
+
+fn key_detail_json(detail: &KeyDetail) -> Value {
+  let locations: Vec<Value> = detail
+    .locations
+    .iter()
+    .map(|location| {
+      json!({
+        "filePath": location.file_path,
+        "line": location.line,
+        "column": location.column,
+        "type": location.r#type.as_str(),
+      })
+    })
+    .collect();
+
+  let mut value = json!({ "key": detail.key, "locations": locations });
+  if let Some(default_value) = &detail.default_value {
+    value["defaultValue"] = json!(default_value);
+  }
+  value
+}
+
+fn diagnostic_json(diagnostic: &Diagnostic) -> Value {
+  let mut value = json!({
+    "filePath": diagnostic.file_path,
+    "message": diagnostic.message,
+    "severity": diagnostic.severity.as_str(),
+  });
+  if let Some(span) = &diagnostic.span {
+    value["span"] = json!({
+      "start": span.start,
+      "end": span.end,
+      "line": span.line,
+      "column": span.column,
+    });
+  }
+  value
+}
+
+/// Namespaces sorted by name so the output does not change between runs
+fn sorted_object(entries: impl Iterator<Item = (String, Value)>) -> Value {
+  let sorted: BTreeMap<String, Value> = entries.collect();
+  Value::Object(sorted.into_iter().collect::<Map<_, _>>())
+}
+
+fn read_json(file_path: &str) -> Result<Value, String> {
+  let content =
+    fs::read_to_string(file_path).map_err(|err| format!("Unable to read {file_path}: {err}"))?;
+  serde_json::from_str(&content).map_err(|err| format!("Invalid JSON in {file_path}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{parse_args, read_scan, run, scan_json, OutputFormat};
+  use crate::analyzer::test_utils::test_path;
+  use crate::error::ScanError;
+
+  fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+  }
+
+  #[test]
+  fn parses_flags_and_entries() {
+    let options = parse_args(args(&[
+      "src/a.ts",
+      "--tsconfig=tsconfig.json",
+      "-x",
+      "react",
+      "--format",
+      "detailed",
+      "-e",
+      "src/b.ts",
+    ]))
+    .unwrap();
+
+    assert_eq!(options.entries, vec!["src/a.ts", "src/b.ts"]);
+    assert_eq!(options.tsconfig.as_deref(), Some("tsconfig.json"));
+    assert_eq!(options.externals, vec!["react"]);
+    assert_eq!(options.format, Some(OutputFormat::Detailed));
+    assert!(parse_args(args(&["--format", "yaml"])).is_err());
+    assert!(parse_args(args(&["--threads"])).is_err());
+    assert!(parse_args(args(&["--verbose"])).is_err());
+  }
+
+  #[test]
+  fn requires_existing_entries() {
+    let options = parse_args(args(&[])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    assert!(matches!(
+      scan_json(payload, &OutputFormat::Keys),
+      Err(ScanError::EmptyEntries)
+    ));
+
+    let options = parse_args(args(&["not/exists.ts"])).unwrap();
+    assert_eq!(
+      read_scan(&options).err(),
+      Some("entry not/exists.ts does not exist".to_string())
+    );
+  }
+
+  #[test]
+  fn exits_with_bad_usage_without_script_entries() {
+    assert_eq!(run(args(&["tests/fake-project/locales/**/*.json"])), 2);
+    assert_eq!(run(args(&["not/exists.ts"])), 1);
+  }
+
+  #[test]
+  fn scans_the_entries() {
+    let options = parse_args(args(&[&test_path("TransComp.tsx")])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+
+    let (result, _) = scan_json(payload, &OutputFormat::Keys).unwrap();
+    assert_eq!(result["default"][0], "TRANS_COMPONENT");
+
+    let options = parse_args(args(&[&test_path("TransComp.tsx")])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    let (result, _) = scan_json(payload, &OutputFormat::Detailed).unwrap();
+    let location = &result["namespaces"]["default"][0]["locations"][0];
+    assert_eq!(location["type"], "TransComp");
+    assert!(location["filePath"]
+      .as_str()
+      .unwrap()
+      .ends_with("TransComp.tsx"));
+  }
+
+  #[test]
+  fn scans_the_included_files() {
+    let options = parse_args(args(&[
+      &test_path("TransComp.tsx"),
+      "--include",
+      &format!("{}/*.ts", test_path("FixedT")),
+      "--exclude",
+      "**/emails.ts",
+    ]))
+    .unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+
+    let (result, _) = scan_json(payload, &OutputFormat::Keys).unwrap();
+    assert_eq!(result["default"][0], "TRANS_COMPONENT");
+    assert_eq!(result["namespace_2"][0], "FIXED_T_NOTICE");
+
+    let options = parse_args(args(&["--include", "not/exists/*.ts"])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    assert!(matches!(
+      scan_json(payload, &OutputFormat::Keys),
+      Err(ScanError::EmptyEntries)
+    ));
+  }
+
+  #[test]
+  fn reports_scan_errors() {
+    let options = parse_args(args(&[&test_path("TransComp.tsx"), "-x", "@scope/(ui"])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    let err = scan_json(payload, &OutputFormat::Keys).unwrap_err();
+    assert_eq!(err.code(), "INVALID_EXTERNAL");
+    assert!(err
+      .to_string()
+      .starts_with("invalid external pattern '@scope/(ui'"));
+
+    let options = parse_args(args(&[&test_path("TransComp.tsx")])).unwrap();
+    let mut payload = read_scan(&options).unwrap().payload;
+    payload.entry_paths = vec![];
+    let err = scan_json(payload, &OutputFormat::Keys).unwrap_err();
+    assert_eq!(err.code(), "EMPTY_ENTRIES");
+  }
+
+  #[test]
+  fn fails_on_parse_errors() {
+    let entry = test_path("Diagnostics/index.tsx");
+    let options = parse_args(args(&[&entry])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    let (result, diagnostics) = scan_json(payload, &OutputFormat::Detailed).unwrap();
+    assert_eq!(result["diagnostics"][0]["severity"], "Error");
+    assert_eq!(result["diagnostics"][1]["span"]["line"], 3);
+    assert_eq!(diagnostics.len(), 2);
+
+    let options = parse_args(args(&[&entry, "--fail-on-parse-error"])).unwrap();
+    let payload = read_scan(&options).unwrap().payload;
+    let err = scan_json(payload, &OutputFormat::Keys).unwrap_err();
+    assert_eq!(err.code(), "PARSE_ERROR");
+    assert!(err.to_string().contains("Broken.tsx:6:35 Unexpected token"));
+  }
+}
@@ -1,83 +1,240 @@
+use crate::analyzer::i18n_packages::global_t_names;
+use crate::collector::key_detail::{KeyDetail, KeyLocation};
+use crate::collector::post_collector::ResolvedKey;
 use crate::collector::walker::Walker;
+use crate::config::ConfigError;
+use crate::diagnostics::Diagnostic;
+use crate::key_config::KeyConfig;
+use crate::module_cache::ModuleCache;
+use crate::node::node::Node;
 use crate::node::node_store::NodeStore;
-use oxc_allocator::Allocator;
+use crate::template_extractor::TemplateExtractor;
 use oxc_ast_visit::walk;
-use oxc_minifier::{CompressOptions, MangleOptions, Minifier, MinifierOptions};
-use oxc_parser::Parser;
-use oxc_semantic::SemanticBuilder;
-use std::collections::HashMap;
-use std::fs;
+use rayon::prelude::*;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 pub struct Collector {
   node_store: NodeStore,
-  allocator: Allocator,
+  module_cache: ModuleCache,
+  pub key_config: KeyConfig,
   pub i18n_namespaces: HashMap<String, Vec<String>>,
+  // { namespace: { key: locations } }
+  pub i18n_key_locations: HashMap<String, HashMap<String, Vec<KeyLocation>>>,
+  // { namespace: { key: default value } }
+  pub i18n_default_values: HashMap<String, HashMap<String, String>>,
 }
 
 impl Collector {
-  pub fn new(nodes: NodeStore) -> Self {
+  pub fn new(nodes: NodeStore, module_cache: ModuleCache, key_config: KeyConfig) -> Self {
     Self {
       node_store: nodes,
-      allocator: Allocator::default(),
+      module_cache,
+      key_config,
       i18n_namespaces: HashMap::new(),
+      i18n_key_locations: HashMap::new(),
+      i18n_default_values: HashMap::new(),
     }
   }
 
   pub fn collect_keys(&mut self) -> &mut Self {
-    let i18n_nodes = self.node_store.get_all_i18n_nodes();
-
-    // let post_collects =
-    for (_, node) in i18n_nodes.iter() {
-      if node.file_path.ends_with(".d.ts") {
-        log::debug!(
-          "[i18n-scanner-rs] skipping declaration file '{}'",
-          node.file_path
-        );
-        continue;
-      }
-
-      let source_text = match fs::read_to_string(node.file_path.as_str()) {
-        Ok(text) => text,
-        Err(err) => {
-          log::warn!(
-            "[i18n-scanner-rs] Unable to read file at {}: {err}",
-            node.file_path
-          );
-          continue;
-        }
+    let mut i18n_nodes: Vec<Arc<Node>> =
+      self.node_store.get_all_i18n_nodes().into_values().collect();
+    // Merge in a stable order so the keys do not depend on which thread finished first
+    i18n_nodes.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+
+    let file_keys: Vec<FileKeys> = i18n_nodes
+      .par_iter()
+      .filter_map(|node| self.collect_file(node))
+      .collect();
+
+    for file_keys in file_keys {
+      file_keys
+        .i18n_namespaces
+        .into_iter()
+        .for_each(|(namespace, keys)| {
+          self
+            .i18n_namespaces
+            .entry(namespace)
+            .or_default()
+            .extend(keys);
+        });
+
+      file_keys
+        .i18n_key_locations
+        .into_iter()
+        .for_each(|(namespace, key_locations)| {
+          for (key, locations) in key_locations {
+            insert_locations(&mut self.i18n_key_locations, &namespace, key, locations);
+          }
+        });
+
+      file_keys
+        .i18n_default_values
+        .into_iter()
+        .for_each(|(namespace, default_values)| {
+          insert_default_values(&mut self.i18n_default_values, &namespace, default_values);
+        });
+
+      file_keys
+        .post_keys
+        .into_iter()
+        .for_each(|(namespace, keys)| {
+          for ResolvedKey {
+            key,
+            key_prefix,
+            default_value,
+            location,
+          } in keys
+          {
+            let key = match key_prefix {
+              Some(key_prefix) => self.key_config.prefix_key(&key_prefix, &key),
+              None => key,
+            };
+            let (namespace, key) = match self.key_config.split_namespace(&key) {
+              Some((namespace, key)) => (namespace.to_string(), key),
+              None => (namespace.clone(), key),
+            };
+            if let Some(default_value) = default_value {
+              insert_default_values(
+                &mut self.i18n_default_values,
+                &namespace,
+                HashMap::from([(key.clone(), default_value)]),
+              );
+            }
+            self
+              .i18n_namespaces
+              .entry(namespace.to_string())
+              .or_default()
+              .push(key.clone());
+            insert_locations(
+              &mut self.i18n_key_locations,
+              &namespace,
+              key,
+              vec![location],
+            );
+          }
+        });
+    }
+    self
+  }
+
+  /// Merges the keys read by the template extractors, a key without a namespace
+  /// is read like a `t` call without options, fails on the first invalid rule
+  pub fn collect_template_keys(
+    &mut self,
+    extractors: &[TemplateExtractor],
+  ) -> Result<&mut Self, ConfigError> {
+    let diagnostics = &self.module_cache.diagnostics;
+    let mut template_keys = vec![];
+    for (index, extractor) in extractors.iter().enumerate() {
+      let keys = extractor.extract(diagnostics).map_err(|err| ConfigError {
+        field: format!("templateExtractors[{index}].{}", err.field),
+        ..err
+      })?;
+      template_keys.extend(keys);
+    }
+
+    for template_key in template_keys {
+      let (namespace, key) = match template_key.namespace {
+        Some(namespace) => (namespace, template_key.key),
+        None => match self.key_config.split_namespace(&template_key.key) {
+          Some((namespace, key)) => (namespace.to_string(), key),
+          None => ("default".to_string(), template_key.key),
+        },
       };
-      let parser = Parser::new(&self.allocator, &source_text, node.source_type);
-      let mut program = parser.parse().program;
 
-      Minifier::new(MinifierOptions {
-        mangle: Some(MangleOptions::default()),
-        compress: Some(CompressOptions::safest()),
-      })
-      .minify(&self.allocator, &mut program);
-
-      let semantic = SemanticBuilder::new().build(&program);
-      let mut walker = Walker::new(node.clone(), &semantic.semantic);
-
-      walk::walk_program(&mut walker, &program);
-
-      walker.i18n_namespaces.iter().for_each(|(namespace, keys)| {
-        self
-          .i18n_namespaces
-          .entry(namespace.to_string())
-          .or_default()
-          .extend(keys.iter().cloned());
-      });
-
-      let post_keys = walker.post_collects.resolve_pending_keys(&self.node_store);
-      post_keys.iter().for_each(|(namespace, keys)| {
-        self
-          .i18n_namespaces
-          .entry(namespace.to_string())
-          .or_default()
-          .extend(keys.iter().cloned());
-      });
+      self
+        .i18n_namespaces
+        .entry(namespace.clone())
+        .or_default()
+        .push(key.clone());
+      insert_locations(
+        &mut self.i18n_key_locations,
+        &namespace,
+        key,
+        vec![template_key.location],
+      );
+    }
+    Ok(self)
+  }
+
+  fn collect_file(&self, node: &Arc<Node>) -> Option<FileKeys> {
+    if node.file_path.ends_with(".d.ts") {
+      log::debug!(
+        "[i18n-scanner-rs] skipping declaration file '{}'",
+        node.file_path
+      );
+      return None;
+    }
+
+    let module = self.module_cache.get(node.file_path.as_str())?;
+    let program = module.program();
+
+    let mut walker = Walker::new(
+      node.clone(),
+      module.semantic(),
+      self.module_cache.clone(),
+      self.key_config.clone(),
+    );
+
+    walk::walk_program(&mut walker, program);
+    for name in global_t_names(&node.file_path) {
+      walker.read_global_t(name);
     }
+
+    let post_keys = walker
+      .post_collects
+      .resolve_pending_keys(&self.node_store, &self.module_cache);
+
+    Some(FileKeys {
+      i18n_namespaces: walker.i18n_namespaces,
+      i18n_key_locations: walker.i18n_key_locations,
+      i18n_default_values: walker.i18n_default_values,
+      post_keys,
+    })
+  }
+
+  /// Files which could not be read or parsed and imports which could not be resolved
+  pub fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    self.module_cache.diagnostics.to_vec()
+  }
+
+  /// Same shape as `i18n_namespaces` but every key carries where it was read from
+  pub fn get_key_details(&self) -> HashMap<String, Vec<KeyDetail>> {
+    self
+      .i18n_namespaces
+      .iter()
+      .map(|(namespace, keys)| {
+        let key_locations = self.i18n_key_locations.get(namespace);
+        let default_values = self.i18n_default_values.get(namespace);
+        let mut seen = HashSet::new();
+        let details = keys
+          .iter()
+          .filter(|key| seen.insert(key.as_str()))
+          .map(|key| KeyDetail {
+            key: key.clone(),
+            locations: key_locations
+              .and_then(|locations| locations.get(key))
+              .cloned()
+              .unwrap_or_default(),
+            default_value: default_values.and_then(|values| values.get(key)).cloned(),
+          })
+          .collect();
+
+        (namespace.clone(), details)
+      })
+      .collect()
+  }
+
+  /// Same as `i18n_namespaces` but the keys are nested by the key separator
+  pub fn get_key_trees(&self) -> HashMap<String, Value> {
     self
+      .i18n_namespaces
+      .iter()
+      .map(|(namespace, keys)| (namespace.clone(), self.key_config.key_tree(keys)))
+      .collect()
   }
 
   pub fn get_keys(&self, namespace: &str) -> Vec<String> {
@@ -91,13 +248,51 @@ impl Collector {
   }
 }
 
+/// Keys collected from a single file, merged into the collector once every file is walked
+struct FileKeys {
+  i18n_namespaces: HashMap<String, Vec<String>>,
+  i18n_key_locations: HashMap<String, HashMap<String, Vec<KeyLocation>>>,
+  i18n_default_values: HashMap<String, HashMap<String, String>>,
+  post_keys: HashMap<String, Vec<ResolvedKey>>,
+}
+
+/// Keeps the first default value collected for a key
+fn insert_default_values(
+  default_values: &mut HashMap<String, HashMap<String, String>>,
+  namespace: &str,
+  values: HashMap<String, String>,
+) {
+  let namespace_values = default_values.entry(namespace.to_string()).or_default();
+  for (key, value) in values {
+    namespace_values.entry(key).or_insert(value);
+  }
+}
+
+fn insert_locations(
+  key_locations: &mut HashMap<String, HashMap<String, Vec<KeyLocation>>>,
+  namespace: &str,
+  key: String,
+  locations: Vec<KeyLocation>,
+) {
+  key_locations
+    .entry(namespace.to_string())
+    .or_default()
+    .entry(key)
+    .or_default()
+    .extend(locations);
+}
+
 #[cfg(test)]
 mod tests {
   use crate::analyzer::i18n_packages::{I18nPackage, Member};
-  use crate::analyzer::test_utils::make_custom_i18n_package;
+  use crate::analyzer::test_utils::{make_custom_i18n_package, test_path};
+  use crate::collector::collector::Collector;
+  use crate::collector::key_detail::KeyLocation;
   use crate::collector::test_utils::{collect, collect_with_options};
+  use crate::diagnostics::DiagnosticSeverity;
   use crate::key_match;
-  use crate::node::i18n_types::I18nType;
+  use crate::node::i18n_types::{I18nType, KeyMethod};
+  use crate::template_extractor::TemplateExtractor;
 
   #[test]
   fn full_collect() {
@@ -107,7 +302,7 @@ mod tests {
 
     println!("default {:?}", collector.get_keys("default"));
 
-    assert_eq!(collector.get_keys("default").len(), 19);
+    assert_eq!(collector.get_keys("default").len(), 21);
     assert_eq!(collector.get_keys("namespace_1").len(), 2);
     assert_eq!(collector.get_keys("namespace_2").len(), 1);
     assert_eq!(collector.get_keys("namespace_3").len(), 2);
@@ -117,6 +312,79 @@ mod tests {
     assert_eq!(collector.get_keys("namespace_translation").len(), 1);
   }
 
+  fn find_location(collector: &Collector, namespace: &str, key: &str) -> KeyLocation {
+    let details = collector.get_key_details();
+    let detail = details
+      .get(namespace)
+      .and_then(|details| details.iter().find(|detail| detail.key == key))
+      .unwrap_or_else(|| panic!("missing key {namespace}:{key}"));
+
+    assert_eq!(detail.locations.len(), 1);
+    detail.locations[0].clone()
+  }
+
+  #[test]
+  fn key_location_from_hook() {
+    let (_, collector) = collect("HookWithNamespace.tsx".into(), None);
+    let location = find_location(&collector, "namespace_1", "HOOK_WITH_NAMESPACE");
+
+    assert!(location.file_path.ends_with("HookWithNamespace.tsx"));
+    assert_eq!((location.line, location.column), (7, 12));
+    assert!(matches!(location.r#type, I18nType::Hook));
+  }
+
+  #[test]
+  fn key_location_from_trans_component() {
+    let (_, collector) = collect("TransComp.tsx".into(), None);
+    let location = find_location(&collector, "default", "TRANS_COMPONENT");
+
+    assert_eq!((location.line, location.column), (6, 7));
+    assert!(matches!(location.r#type, I18nType::TransComp));
+  }
+
+  #[test]
+  fn key_location_from_post_collector() {
+    let (_, collector) = collect("PostCollectorCrossFile/Component.tsx".into(), None);
+    let location = find_location(&collector, "default", "POST_COLLECTOR_CROSS_FILE");
+
+    assert!(location
+      .file_path
+      .ends_with("PostCollectorCrossFile/Component.tsx"));
+    assert_eq!((location.line, location.column), (7, 14));
+  }
+
+  #[test]
+  fn key_location_from_vue_template() {
+    let (_, collector) = collect("VueComp.vue".into(), None);
+
+    let location = find_location(&collector, "default", "VUE_SETUP_T");
+    assert!(location.file_path.ends_with("VueComp.vue"));
+    assert_eq!((location.line, location.column), (4, 12));
+
+    let location = find_location(&collector, "default", "VUE_SCRIPT");
+    assert_eq!((location.line, location.column), (16, 25));
+  }
+
+  #[test]
+  fn default_values_from_t_and_trans() {
+    let (_, collector) = collect("DefaultValue.tsx".into(), None);
+    let default_values = &collector.i18n_default_values["default"];
+
+    assert_eq!(default_values["DEFAULT_VALUE_INLINE"], "Save");
+    assert_eq!(default_values["DEFAULT_VALUE_OPTION"], "Cancel");
+    assert_eq!(
+      default_values["DEFAULT_VALUE_TRANS"],
+      "Hello <1>{{name}}</1>, you have <strong>new</strong> messages.<br/>"
+    );
+
+    let details = collector.get_key_details();
+    let detail = details["default"]
+      .iter()
+      .find(|detail| detail.key == "DEFAULT_VALUE_INLINE")
+      .unwrap();
+    assert_eq!(detail.default_value.as_deref(), Some("Save"));
+  }
+
   key_match!(
     nothing_about_i18n,
     "NothingAboutI18n.tsx".into(),
@@ -145,6 +413,46 @@ mod tests {
     "namespace_2".into(),
     vec!["NAMESPACE_OVERRIDE"]
   );
+  key_match!(
+    namespace_prefix,
+    "NamespacePrefix.tsx".into(),
+    "namespace_2",
+    vec!["NAMESPACE_PREFIX"]
+  );
+  key_match!(
+    key_prefix,
+    "KeyPrefix.tsx".into(),
+    "namespace_1",
+    vec!["page.header.KEY_PREFIX_TITLE"]
+  );
+  key_match!(
+    key_prefix_instance,
+    "KeyPrefixInstance.tsx".into(),
+    "namespace_1",
+    vec![
+      "page.KEY_PREFIX_T",
+      "KEY_PREFIX_EXISTS",
+      "KEY_PREFIX_INSTANCE_T",
+      "KEY_PREFIX_NAMESPACE_IMPORT_T"
+    ]
+  );
+  key_match!(
+    plural_context,
+    "PluralContext.tsx".into(),
+    vec![
+      "PLURAL_ITEM_one",
+      "PLURAL_ITEM_other",
+      "TRANS_PLURAL_ITEM_one",
+      "TRANS_PLURAL_ITEM_other",
+      "CONTEXT_FRIEND",
+      "CONTEXT_FRIEND_male"
+    ]
+  );
+  key_match!(
+    trans_children_key,
+    "TransChildrenKey.tsx".into(),
+    vec!["Hello <1>{{name}}</1>", "Welcome <strong>back</strong>"]
+  );
   key_match!(global_t, "globalT.ts".into(), vec!["GLOBAL_T"]);
   key_match!(rename_both, "RenameBoth.tsx".into(), vec!["RENAME_BOTH"]);
   key_match!(rename_t, "RenameT.tsx".into(), vec!["RENAME_T"]);
@@ -163,6 +471,196 @@ mod tests {
 
   key_match!(member_t, "memberT.ts".into(), vec!["MEMBER_T"]);
 
+  key_match!(
+    use_i18n_only_from_vue_i18n,
+    "UseI18nFromReact.tsx".into(),
+    vec!["USE_TRANSLATION_KEY"]
+  );
+
+  key_match!(
+    vue_options_component,
+    "VueOptionsComp.vue".into(),
+    vec![
+      "VUE_OPTIONS_TITLE",
+      "VUE_OPTIONS_TEXT",
+      "VUE_OPTIONS_TC_TEMPLATE",
+      "VUE_OPTIONS_KEYPATH",
+      "VUE_OPTIONS_THIS_T",
+      "VUE_OPTIONS_THIS_TC"
+    ]
+  );
+
+  key_match!(
+    vue_component,
+    "VueComp.vue".into(),
+    vec![
+      "VUE_BOUND_ATTRIBUTE",
+      "VUE_SETUP_T",
+      "VUE_KEYPATH",
+      "VUE_DIRECTIVE",
+      "VUE_EVENT",
+      "VUE_GLOBAL_T",
+      "VUE_SCRIPT"
+    ]
+  );
+
+  key_match!(
+    svelte_component,
+    "SvelteComp.svelte".into(),
+    vec![
+      "SVELTE_SCRIPT",
+      "SVELTE_ATTRIBUTE",
+      "SVELTE_MARKUP",
+      "SVELTE_BLOCK",
+      "SVELTE_HANDLER"
+    ]
+  );
+
+  key_match!(
+    astro_component,
+    "AstroComp.astro".into(),
+    vec!["ASTRO_FRONTMATTER", "ASTRO_ATTRIBUTE", "ASTRO_MARKUP"]
+  );
+
+  key_match!(
+    commonjs_require,
+    "CommonJS/index.cjs".into(),
+    vec!["CJS_HOOK", "CJS_T", "CJS_INSTANCE"]
+  );
+
+  key_match!(
+    commonjs_required_custom_hook,
+    "CommonJS/index.cjs".into(),
+    "namespace_2".into(),
+    vec!["CJS_CUSTOM_HOOK"]
+  );
+
+  key_match!(
+    module_typescript,
+    "CommonJS/modern.mts".into(),
+    vec!["MTS_T"]
+  );
+
+  key_match!(
+    key_methods,
+    "KeyMethods.ts".into(),
+    vec!["KEY_METHOD_EXISTS"]
+  );
+
+  key_match!(
+    key_methods_with_namespace,
+    "KeyMethods.ts".into(),
+    "namespace_1",
+    vec!["KEY_METHOD_RESOURCE"]
+  );
+
+  key_match!(
+    custom_key_methods,
+    "custom-i18n/KeyMethods.ts".into(),
+    "default",
+    vec![I18nPackage {
+      package_path: "@custom/i18n".into(),
+      members: vec![Member {
+        ns: None,
+        key_prefix: None,
+        key_index: None,
+        ns_index: None,
+        options_index: None,
+        key_methods: Some(vec![KeyMethod {
+          name: "hasTranslation".into(),
+          key_index: 0,
+          ns_index: None,
+        }]),
+        name: "i18n".into(),
+        r#type: I18nType::ObjectMemberT
+      }]
+    }],
+    vec!["CUSTOM_KEY_METHOD"]
+  );
+
+  key_match!(
+    hook_key_methods,
+    "HookKeyMethods.tsx".into(),
+    vec!["HOOK_KEY_METHOD_EXISTS"]
+  );
+
+  key_match!(
+    hook_key_methods_with_namespace,
+    "HookKeyMethods.tsx".into(),
+    "namespace_1",
+    vec!["HOOK_KEY_METHOD_RESOURCE"]
+  );
+
+  key_match!(
+    custom_hook_key_methods,
+    "custom-i18n/HookKeyMethods.tsx".into(),
+    "default",
+    vec![I18nPackage {
+      package_path: "@custom/i18n".into(),
+      members: vec![
+        Member {
+          ns: None,
+          key_prefix: None,
+          key_index: None,
+          ns_index: None,
+          options_index: None,
+          key_methods: None,
+          name: "useTranslation".into(),
+          r#type: I18nType::Hook
+        },
+        Member {
+          ns: None,
+          key_prefix: None,
+          key_index: None,
+          ns_index: None,
+          options_index: None,
+          key_methods: Some(vec![KeyMethod {
+            name: "hasTranslation".into(),
+            key_index: 0,
+            ns_index: None,
+          }]),
+          name: "i18n".into(),
+          r#type: I18nType::ObjectMemberT
+        }
+      ]
+    }],
+    vec!["CUSTOM_HOOK_KEY_METHOD"]
+  );
+
+  key_match!(
+    custom_t_arguments,
+    "custom-i18n/TranslateArguments.ts".into(),
+    "namespace_1",
+    vec![I18nPackage {
+      package_path: "@custom/i18n".into(),
+      members: vec![Member {
+        ns: None,
+        key_prefix: None,
+        key_index: Some(1),
+        ns_index: Some(0),
+        options_index: Some(2),
+        key_methods: None,
+        name: "translate".into(),
+        r#type: I18nType::TMethod
+      }]
+    }],
+    vec!["TRANSLATE_ARGUMENTS_one", "TRANSLATE_ARGUMENTS_other"]
+  );
+
+  key_match!(
+    fixed_t,
+    "FixedT/index.ts".into(),
+    "namespace_1",
+    vec!["welcome.FIXED_T_SUBJECT", "welcome.FIXED_T_BODY"]
+  );
+
+  key_match!(
+    fixed_t_without_prefix,
+    "FixedT/index.ts".into(),
+    "namespace_2",
+    vec!["FIXED_T_NOTICE"]
+  );
+
   key_match!(
     member_call_t,
     "MemberCallT.tsx".into(),
@@ -206,12 +704,14 @@ mod tests {
     vec!["WRAPPED_USE_TRANSLATION"]
   );
 
+  key_match!(hook_chain, "HookChain/index.tsx".into(), vec!["HOOK_CHAIN"]);
+
   key_match!(hoc_component, "HocComp.tsx".into(), vec!["HOC_COMPONENT"]);
 
   key_match!(
     post_collector_cross_file,
     "PostCollectorCrossFile/Component.tsx".into(),
-    vec!["POST_COLLECTOR_CROSS_FILE"]
+    vec!["POST_COLLECTOR_CROSS_FILE", "POST_COLLECTOR_V5"]
   );
 
   key_match!(
@@ -231,6 +731,11 @@ mod tests {
     "I18nCodeDynamic.tsx".into(),
     vec!["I18N_CODE_DYNAMIC_hello", "I18N_CODE_DYNAMIC_world"]
   );
+  key_match!(
+    i18n_code_constant_folding,
+    "I18nCodeConstantFolding.tsx".into(),
+    vec!["I18N_CODE_CONSTANT_FOLDING_V2"]
+  );
   key_match!(t_array, "TArray.tsx".into(), vec!["T_ARRAY"]);
   key_match!(
     t_array_custom,
@@ -246,6 +751,11 @@ mod tests {
       package_path: "@custom/i18n".into(),
       members: vec![Member {
         ns: None,
+        key_prefix: None,
+        key_index: None,
+        ns_index: None,
+        options_index: None,
+        key_methods: None,
         name: "useTranslation".into(),
         r#type: I18nType::Hook
       }]
@@ -281,4 +791,72 @@ mod tests {
     assert_eq!(collector.get_keys("default").len(), 16);
     assert_eq!(collector.get_keys("namespace_3").len(), 2);
   }
+
+  #[test]
+  fn collect_template_keys() {
+    let (_, mut collector) = collect("TransComp.tsx".into(), None);
+    collector
+      .collect_template_keys(&[TemplateExtractor {
+        files: vec![test_path("templates/page.html")],
+        pipes: None,
+        helpers: None,
+        patterns: None,
+      }])
+      .unwrap();
+
+    let mut keys = collector.get_keys("default");
+    keys.sort();
+    assert_eq!(
+      keys,
+      vec![
+        "HTML_HELPER",
+        "HTML_PIPE",
+        "HTML_PIPE_ARGS",
+        "TRANS_COMPONENT"
+      ]
+    );
+    assert_eq!(collector.get_keys("common"), vec!["HTML_NS"]);
+
+    let location = find_location(&collector, "common", "HTML_NS");
+    assert!(location.file_path.ends_with("page.html"));
+    assert_eq!((location.line, location.column), (5, 15));
+  }
+
+  #[test]
+  fn collect_diagnostics() {
+    let (_, collector) = collect("Diagnostics/index.tsx".into(), None);
+    assert_eq!(collector.get_keys("default"), vec!["DIAGNOSTICS_PAGE"]);
+
+    let diagnostics = collector.get_diagnostics();
+    assert_eq!(diagnostics.len(), 2);
+
+    let parse_error = &diagnostics[0];
+    assert!(parse_error.file_path.ends_with("Diagnostics/Broken.tsx"));
+    assert_eq!(parse_error.severity, DiagnosticSeverity::Error);
+    let span = parse_error.span.as_ref().unwrap();
+    assert_eq!((span.line, span.column), (6, 35));
+
+    // `fs` is a Node builtin, only the missing file is reported
+    let unresolved = &diagnostics[1];
+    assert!(unresolved.file_path.ends_with("Diagnostics/index.tsx"));
+    assert_eq!(unresolved.severity, DiagnosticSeverity::Warning);
+    assert!(unresolved
+      .message
+      .starts_with("Unable to resolve './missing'"));
+    let span = unresolved.span.as_ref().unwrap();
+    assert_eq!((span.line, span.column), (3, 23));
+  }
+
+  #[test]
+  fn collect_is_stable_across_thread_counts() {
+    let collect_on = |threads: usize| {
+      rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+        .install(|| collect("index.tsx".into(), None).1.i18n_namespaces)
+    };
+
+    assert_eq!(collect_on(1), collect_on(4));
+  }
 }
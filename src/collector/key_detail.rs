@@ -0,0 +1,74 @@
+use crate::node::i18n_types::I18nType;
+
+/// Where a key was read from, `line` and `column` are 1-based
+#[derive(Clone, Debug)]
+#[napi(object)]
+pub struct KeyLocation {
+  pub file_path: String,
+  pub line: u32,
+  pub column: u32,
+  pub r#type: I18nType,
+}
+
+#[derive(Clone, Debug)]
+#[napi(object)]
+pub struct KeyDetail {
+  pub key: String,
+  pub locations: Vec<KeyLocation>,
+  /// The default value the key is used with, e.g. `t('save', 'Save')` or the `Trans` children
+  pub default_value: Option<String>,
+}
+
+/// Maps byte offsets of a source text to line/column pairs
+pub struct LineIndex<'a> {
+  source_text: &'a str,
+  line_starts: Vec<u32>,
+}
+
+impl<'a> LineIndex<'a> {
+  pub fn new(source_text: &'a str) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(
+        source_text
+          .match_indices('\n')
+          .map(|(index, _)| index as u32 + 1),
+      )
+      .collect();
+
+    Self {
+      source_text,
+      line_starts,
+    }
+  }
+
+  pub fn line_column(&self, offset: u32) -> (u32, u32) {
+    let line = self
+      .line_starts
+      .partition_point(|start| *start <= offset)
+      .max(1);
+    let line_start = self.line_starts[line - 1] as usize;
+    let end = (offset as usize).min(self.source_text.len());
+    // Columns count characters rather than bytes so non-ASCII sources line up with editors.
+    let column = self
+      .source_text
+      .get(line_start..end)
+      .map_or(0, |text| text.chars().count());
+
+    (line as u32, column as u32 + 1)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LineIndex;
+
+  #[test]
+  fn maps_offsets_to_line_column() {
+    let index = LineIndex::new("const a = 1;\nt('KEY');\n  t('ÄBC')");
+
+    assert_eq!(index.line_column(0), (1, 1));
+    assert_eq!(index.line_column(13), (2, 1));
+    assert_eq!(index.line_column(15), (2, 3));
+    assert_eq!(index.line_column(25), (3, 3));
+  }
+}
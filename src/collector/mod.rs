@@ -1,5 +1,7 @@
 pub mod collector;
+pub mod key_detail;
 mod post_collector;
 pub mod test_utils;
+mod trans_children;
 mod visit;
 mod walker;
@@ -1,25 +1,52 @@
+use crate::collector::key_detail::KeyLocation;
+use crate::module_cache::ModuleCache;
 use crate::node::node_store::NodeStore;
-use oxc_allocator::Allocator;
+use crate::walk_utils::number_to_string;
 use oxc_ast::ast::{
   BindingPattern, Declaration, ExportDefaultDeclarationKind, Expression,
-  ImportDeclarationSpecifier, SourceType, Statement,
+  ImportDeclarationSpecifier, Statement,
 };
-use oxc_parser::Parser;
 use std::collections::{HashMap, HashSet};
 
 pub struct PendingIdentifierKey {
   pub node_path: String,
   pub namespace: String,
   pub identifier: String,
+  // Plural and context suffixes of the t call, appended to the resolved key
+  pub suffixes: Vec<String>,
+  // The `keyPrefix` of the hook, prepended to the resolved key
+  pub key_prefix: Option<String>,
+  pub default_value: Option<String>,
+  pub location: KeyLocation,
+}
+
+/// A pending key once its identifier is resolved to a string
+pub struct ResolvedKey {
+  pub key: String,
+  pub key_prefix: Option<String>,
+  pub default_value: Option<String>,
+  pub location: KeyLocation,
 }
 
 #[derive(Clone, Debug)]
 enum ValueExpr {
   String(String),
+  // Only read as an operand of a concatenation, `t(NUM)` is no key
+  Number(String),
   Identifier(String),
   Binary(Box<ValueExpr>, Box<ValueExpr>),
 }
 
+impl ValueExpr {
+  /// The text of a resolved string or number as an operand of a concatenation
+  fn operand(&self) -> Option<&str> {
+    match self {
+      ValueExpr::String(text) | ValueExpr::Number(text) => Some(text),
+      _ => None,
+    }
+  }
+}
+
 #[derive(Default)]
 struct ModuleInfo {
   locals: HashMap<String, ValueExpr>,
@@ -31,6 +58,7 @@ struct ModuleInfo {
 
 struct ResolveContext<'a> {
   node_store: &'a NodeStore,
+  modules: &'a ModuleCache,
   module_cache: HashMap<String, ModuleInfo>,
 }
 
@@ -45,23 +73,19 @@ impl PostCollector {
     }
   }
 
-  pub fn add_pending_identifier_key(
-    &mut self,
-    node_path: String,
-    namespace: String,
-    identifier: String,
-  ) {
-    self.pending_identifier_keys.push(PendingIdentifierKey {
-      node_path,
-      namespace,
-      identifier,
-    });
+  pub fn add_pending_identifier_key(&mut self, pending: PendingIdentifierKey) {
+    self.pending_identifier_keys.push(pending);
   }
 
-  pub fn resolve_pending_keys(&self, node_store: &NodeStore) -> HashMap<String, Vec<String>> {
-    let mut resolved = HashMap::<String, Vec<String>>::new();
+  pub fn resolve_pending_keys(
+    &self,
+    node_store: &NodeStore,
+    modules: &ModuleCache,
+  ) -> HashMap<String, Vec<ResolvedKey>> {
+    let mut resolved = HashMap::<String, Vec<ResolvedKey>>::new();
     let mut ctx = ResolveContext {
       node_store,
+      modules,
       module_cache: HashMap::new(),
     };
 
@@ -73,10 +97,18 @@ impl PostCollector {
         &pending.identifier,
         &mut visited,
       ) {
-        resolved
-          .entry(pending.namespace.clone())
-          .or_default()
-          .push(value);
+        let ValueExpr::String(value) = value else {
+          continue;
+        };
+        let keys = resolved.entry(pending.namespace.clone()).or_default();
+        for suffix in &pending.suffixes {
+          keys.push(ResolvedKey {
+            key: format!("{value}{suffix}"),
+            key_prefix: pending.key_prefix.clone(),
+            default_value: pending.default_value.clone(),
+            location: pending.location.clone(),
+          });
+        }
       }
     }
 
@@ -88,7 +120,7 @@ impl PostCollector {
     file_path: &str,
     identifier: &str,
     visited: &mut HashSet<String>,
-  ) -> Option<String> {
+  ) -> Option<ValueExpr> {
     let visit_key = format!("{}::{}", file_path, identifier);
     if !visited.insert(visit_key) {
       // Guard against cycles across aliases/import-export chains.
@@ -117,7 +149,7 @@ impl PostCollector {
     file_path: &str,
     exported_name: &str,
     visited: &mut HashSet<String>,
-  ) -> Option<String> {
+  ) -> Option<ValueExpr> {
     let module = Self::load_module_info(ctx, file_path)?;
 
     if let Some(export_expr) = module.export_values.get(exported_name).cloned() {
@@ -137,32 +169,34 @@ impl PostCollector {
     None
   }
 
+  /// Resolves to a `String` or a `Number`, a concatenation is a string
   fn resolve_value_expr(
     ctx: &mut ResolveContext,
     file_path: &str,
     expr: &ValueExpr,
     visited: &mut HashSet<String>,
-  ) -> Option<String> {
+  ) -> Option<ValueExpr> {
     match expr {
-      ValueExpr::String(s) => Some(s.clone()),
+      ValueExpr::String(_) | ValueExpr::Number(_) => Some(expr.clone()),
       ValueExpr::Identifier(ident) => {
         Self::resolve_identifier_in_file(ctx, file_path, ident.as_str(), visited)
       }
       ValueExpr::Binary(left, right) => {
         let left_value = Self::resolve_value_expr(ctx, file_path, left, visited)?;
         let right_value = Self::resolve_value_expr(ctx, file_path, right, visited)?;
-        Some(format!("{}{}", left_value, right_value))
+        Some(ValueExpr::String(format!(
+          "{}{}",
+          left_value.operand()?,
+          right_value.operand()?
+        )))
       }
     }
   }
 
   fn load_module_info<'a>(ctx: &'a mut ResolveContext, file_path: &str) -> Option<&'a ModuleInfo> {
     if !ctx.module_cache.contains_key(file_path) {
-      let source_text = std::fs::read_to_string(file_path).ok()?;
-      let allocator = Allocator::default();
-      let source_type = SourceType::from_path(file_path).ok()?;
-      let parser = Parser::new(&allocator, &source_text, source_type);
-      let program = parser.parse().program;
+      let parsed = ctx.modules.get(file_path)?;
+      let program = parsed.program();
 
       let mut module = ModuleInfo::default();
 
@@ -235,8 +269,9 @@ impl PostCollector {
   }
 
   fn extract_value_expr(expr: &Expression) -> Option<ValueExpr> {
-    match expr {
+    match expr.get_inner_expression() {
       Expression::StringLiteral(s) => Some(ValueExpr::String(s.value.to_string())),
+      Expression::NumericLiteral(n) => Some(ValueExpr::Number(number_to_string(n.value))),
       Expression::TemplateLiteral(tpl) => {
         if tpl.expressions.is_empty() && tpl.quasis.len() == 1 {
           Some(ValueExpr::String(tpl.quasis[0].value.raw.to_string()))
@@ -2,6 +2,7 @@ use crate::analyzer::analyzer::Analyzer;
 use crate::analyzer::i18n_packages::I18nPackage;
 use crate::analyzer::test_utils::analyze_with_options;
 use crate::collector::collector::Collector;
+use crate::key_config::KeyConfig;
 use log::debug;
 
 #[macro_export]
@@ -59,7 +60,11 @@ pub fn collect_with_options(
 
   debug!("with_i18n_nodes: {:?}", with_i18n_nodes.len());
 
-  let mut collector = Collector::new(node_store.clone());
+  let mut collector = Collector::new(
+    node_store.clone(),
+    analyzer.module_cache.clone(),
+    KeyConfig::default(),
+  );
 
   collector.collect_keys();
 
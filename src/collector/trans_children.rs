@@ -0,0 +1,180 @@
+use crate::walk_utils::WalkerUtils;
+use log::debug;
+use oxc_ast::ast::{
+  Expression, JSXAttributeItem, JSXAttributeName, JSXChild, JSXElement, JSXElementName,
+  ObjectPropertyKind, PropertyKey,
+};
+
+/// A child of a `Trans` element, the way it reaches react-i18next's `nodesToString`
+enum TransNode<'b, 'a> {
+  Text(String),
+  Element(&'b JSXElement<'a>),
+  Children(&'b [JSXChild<'a>]),
+  Interpolation(String),
+  Unknown,
+}
+
+/// Serializes the children of a `Trans` element like react-i18next does,
+/// `Hello <b>{{name}}</b><br/>` becomes `Hello <1>{{name}}</1><br/>`
+pub fn serialize_trans_children(
+  children: &[JSXChild],
+  keep_basic_html_nodes_for: &[String],
+  walk_utils: &WalkerUtils,
+) -> String {
+  trans_nodes(children, walk_utils)
+    .into_iter()
+    .enumerate()
+    .map(|(index, node)| match node {
+      TransNode::Text(text) => text,
+      TransNode::Interpolation(name) => format!("{{{{{name}}}}}"),
+      TransNode::Element(element) => {
+        serialize_element(index, element, keep_basic_html_nodes_for, walk_utils)
+      }
+      TransNode::Children(children) => format!(
+        "<{index}>{}</{index}>",
+        serialize_trans_children(children, keep_basic_html_nodes_for, walk_utils)
+      ),
+      TransNode::Unknown => {
+        debug!("Trans child at index {index} can not be serialized");
+        String::new()
+      }
+    })
+    .collect()
+}
+
+fn serialize_element(
+  index: usize,
+  element: &JSXElement,
+  keep_basic_html_nodes_for: &[String],
+  walk_utils: &WalkerUtils,
+) -> String {
+  let element_type = match &element.opening_element.name {
+    JSXElementName::Identifier(ident) => Some(ident.name.as_str()),
+    _ => None,
+  };
+  let should_keep = element_type.is_some_and(|element_type| {
+    keep_basic_html_nodes_for
+      .iter()
+      .any(|keep| keep == element_type)
+  });
+  let is_dynamic_list = element.opening_element.attributes.iter().any(|attribute| {
+    matches!(
+      attribute,
+      JSXAttributeItem::Attribute(attr)
+        if matches!(&attr.name, JSXAttributeName::Identifier(ident) if ident.name == "i18nIsDynamicList")
+    )
+  });
+
+  let children = trans_nodes(&element.children, walk_utils);
+  let props_count = element.opening_element.attributes.len() + usize::from(!children.is_empty());
+
+  match (element_type, children.as_slice()) {
+    (Some(element_type), []) if should_keep && props_count == 0 => format!("<{element_type}/>"),
+    _ if children.is_empty() || is_dynamic_list => format!("<{index}></{index}>"),
+    (Some(element_type), [TransNode::Text(text)]) if should_keep && props_count == 1 => {
+      format!("<{element_type}>{text}</{element_type}>")
+    }
+    _ => format!(
+      "<{index}>{}</{index}>",
+      serialize_trans_children(&element.children, keep_basic_html_nodes_for, walk_utils)
+    ),
+  }
+}
+
+/// The children React would pass, blank JSX text and empty expressions are dropped
+fn trans_nodes<'b, 'a>(
+  children: &'b [JSXChild<'a>],
+  walk_utils: &WalkerUtils,
+) -> Vec<TransNode<'b, 'a>> {
+  children
+    .iter()
+    .filter_map(|child| match child {
+      JSXChild::Text(text) => clean_jsx_text(&text.value).map(TransNode::Text),
+      JSXChild::Element(element) => Some(TransNode::Element(element)),
+      JSXChild::Fragment(fragment) => Some(TransNode::Children(&fragment.children)),
+      JSXChild::ExpressionContainer(container) => {
+        let expr = container.expression.as_expression()?;
+        Some(read_expression_node(expr, walk_utils))
+      }
+      JSXChild::Spread(_) => Some(TransNode::Unknown),
+    })
+    .collect()
+}
+
+fn read_expression_node<'b, 'a>(expr: &Expression, walk_utils: &WalkerUtils) -> TransNode<'b, 'a> {
+  if let Some(text) = walk_utils.read_str_expression(expr) {
+    return TransNode::Text(text);
+  }
+
+  // {{ name }} or {{ amount, format: 'number' }}
+  let Expression::ObjectExpression(obj) = expr.get_inner_expression() else {
+    return TransNode::Unknown;
+  };
+
+  let mut names = vec![];
+  let mut format = None;
+  for prop in &obj.properties {
+    let ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
+      return TransNode::Unknown;
+    };
+    let PropertyKey::StaticIdentifier(prop_name) = &obj_prop.key else {
+      return TransNode::Unknown;
+    };
+
+    if prop_name.name == "format" {
+      format = walk_utils.read_str_expression(&obj_prop.value);
+    } else {
+      names.push(prop_name.name.to_string());
+    }
+  }
+
+  match (names.as_slice(), format) {
+    ([name], Some(format)) => TransNode::Interpolation(format!("{name}, {format}")),
+    ([name], None) => TransNode::Interpolation(name.clone()),
+    _ => TransNode::Unknown,
+  }
+}
+
+/// Trims JSX text the way JSX compilers do, lines are trimmed and joined by a single space
+fn clean_jsx_text(value: &str) -> Option<String> {
+  let lines: Vec<&str> = value
+    .split('\n')
+    .map(|line| line.strip_suffix('\r').unwrap_or(line))
+    .collect();
+  let last_non_empty_line = lines.iter().rposition(|line| !line.trim().is_empty())?;
+
+  let mut text = String::new();
+  for (index, line) in lines.iter().enumerate() {
+    let mut line = line.replace('\t', " ");
+    if index != 0 {
+      line = line.trim_start_matches(' ').to_string();
+    }
+    if index != lines.len() - 1 {
+      line = line.trim_end_matches(' ').to_string();
+    }
+
+    if !line.is_empty() {
+      text.push_str(&line);
+      if index != last_non_empty_line {
+        text.push(' ');
+      }
+    }
+  }
+
+  (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::clean_jsx_text;
+
+  #[test]
+  fn clean_jsx_text_like_compilers() {
+    assert_eq!(clean_jsx_text("Hello "), Some("Hello ".to_string()));
+    assert_eq!(
+      clean_jsx_text("\n    Hello\n    world\n  "),
+      Some("Hello world".to_string())
+    );
+    assert_eq!(clean_jsx_text("\n    \n  "), None);
+  }
+}
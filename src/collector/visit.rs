@@ -1,86 +1,173 @@
 use super::walker::Walker;
+use crate::analyzer::i18n_packages::{default_import_member, preset_key_methods};
 use crate::node::i18n_types::{I18nMember, I18nType};
-use oxc_ast::ast::{ImportDeclaration, ImportDeclarationSpecifier};
-use oxc_ast_visit::Visit;
+use oxc_ast::ast::{
+  BindingIdentifier, BindingPattern, ImportDeclaration, ImportDeclarationSpecifier,
+  VariableDeclarator,
+};
+use oxc_ast_visit::{walk, Visit};
 use std::collections::HashMap;
 
 impl<'a> Visit<'a> for Walker<'a> {
   fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
     if let Some(specifiers) = &it.specifiers {
-      if let Some(i18n_source) = self.node.get_importing_node(&it.source.value) {
-        let members = i18n_source.get_exporting_members();
-        for specifier in specifiers {
-          match specifier {
-            ImportDeclarationSpecifier::ImportSpecifier(s) => {
-              if let Some(Some(member)) = members.get(s.imported.name().as_str()) {
-                match member.r#type {
-                  I18nType::Hook => {
-                    self.read_hook(s, member.ns.clone(), &members);
-                  }
-                  I18nType::TMethod => {
-                    self.register_t_symbol(s.local.symbol_id(), s.local.name.as_str());
-                    self.read_t(s.local.symbol_id(), member.ns.clone());
-                  }
-                  I18nType::ObjectMemberT => {
-                    let translation_names = Walker::collect_t_member_names(&members);
-                    self.register_translation_names(translation_names);
-                    self.read_object_member_t(s.local.symbol_id(), member.ns.clone());
-                  }
-                  I18nType::TransComp => {
-                    self.read_trans_component(s.local.symbol_id(), member.ns.clone());
-                  }
-                  I18nType::TranslationComp => {
-                    self.read_translation_component(s.local.symbol_id(), member.ns.clone());
-                  }
-                  I18nType::HocWrapper => {
-                    self.read_hoc_wrapper(s.local.symbol_id(), member.ns.clone());
-                  }
-                }
-              } else if self.is_standard_hook_export(s.imported.name().as_str()) {
-                let empty_members: HashMap<String, Option<I18nMember>> = HashMap::new();
-                let translation_names = Walker::collect_t_member_names(&empty_members);
-                self.register_translation_names(translation_names.clone());
-                self.read_hook(s, None, &empty_members);
-              } else if s.imported.name().as_str() == "t" {
-                self.register_t_symbol(s.local.symbol_id(), s.local.name.as_str());
-                self.read_t(s.local.symbol_id(), None);
-              }
+      let members = self
+        .node
+        .get_importing_node(&it.source.value)
+        .map(|i18n_source| i18n_source.get_exporting_members());
+
+      for specifier in specifiers {
+        match specifier {
+          ImportDeclarationSpecifier::ImportSpecifier(s) => {
+            self.read_imported_binding(&s.local, s.imported.name().as_str(), members.as_ref());
+          }
+          ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => {
+            // import i18next from 'i18next'
+            if let Some(members) = &members {
+              self.read_default_binding(&s.local, members);
             }
-            ImportDeclarationSpecifier::ImportNamespaceSpecifier(ns_spec) => {
-              // Handle namespace import
-              // For namespace imports like `import * as i18n from 'react-i18next'`
-              // We need to handle calls like `i18n.useTranslation()` and `i18n.t()`
-              if let Some(i18n_source) = self.node.get_importing_node(&it.source.value) {
-                let members = i18n_source.get_exporting_members();
-                let translation_names = Walker::collect_t_member_names(&members);
-                self.register_translation_names(translation_names);
-                // Handle the namespace symbol
-                self.read_namespace_import(ns_spec.local.symbol_id(), &members);
-              }
+          }
+          ImportDeclarationSpecifier::ImportNamespaceSpecifier(ns_spec) => {
+            // Handle namespace import
+            // For namespace imports like `import * as i18n from 'react-i18next'`
+            // We need to handle calls like `i18n.useTranslation()` and `i18n.t()`
+            if let Some(members) = &members {
+              let translation_names = Walker::collect_t_member_names(members);
+              self.register_translation_names(translation_names);
+              // Handle the namespace symbol
+              self.read_namespace_import(ns_spec.local.symbol_id(), members);
             }
-            _ => {}
           }
         }
-      } else {
-        for specifier in specifiers {
-          if let ImportDeclarationSpecifier::ImportSpecifier(s) = specifier {
-            let imported_name = s.imported.name().as_str();
-            if self.is_standard_hook_export(imported_name) {
-              let empty_members: HashMap<String, Option<_>> = HashMap::new();
-              let translation_names = Walker::collect_t_member_names(&empty_members);
-              self.register_translation_names(translation_names.clone());
-              self.read_hook(s, None, &empty_members);
-            } else if imported_name == "t" {
-              self.register_t_symbol(s.local.symbol_id(), s.local.name.as_str());
-              self.read_t(s.local.symbol_id(), None);
+      }
+    }
+  }
+
+  // const { useTranslation } = require('react-i18next');
+  // const i18next = require('i18next');
+  fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+    let source = it
+      .init
+      .as_ref()
+      .and_then(|init| self.walk_utils.read_require_source(init));
+
+    if let Some(source) = source {
+      let members = self
+        .node
+        .get_importing_node(&source.value)
+        .map(|i18n_source| i18n_source.get_exporting_members());
+
+      match &it.id {
+        BindingPattern::BindingIdentifier(ident) => {
+          if let Some(members) = &members {
+            if default_import_member(members).is_some() {
+              self.read_default_binding(ident, members);
+            } else {
+              let translation_names = Walker::collect_t_member_names(members);
+              self.register_translation_names(translation_names);
+              self.read_namespace_import(ident.symbol_id(), members);
             }
           }
         }
+        BindingPattern::ObjectPattern(obj) => {
+          for prop in &obj.properties {
+            let (Some(name), BindingPattern::BindingIdentifier(local)) =
+              (prop.key.static_name(), &prop.value)
+            else {
+              continue;
+            };
+            self.read_imported_binding(local, &name, members.as_ref());
+          }
+        }
+        _ => {}
+      }
+    }
+
+    walk::walk_variable_declarator(self, it);
+  }
+}
+
+impl Walker<'_> {
+  /// Reads the usages of a binding imported by name, `members` are the exports of the
+  /// imported module when it is part of the graph
+  fn read_imported_binding(
+    &mut self,
+    local: &BindingIdentifier,
+    imported_name: &str,
+    members: Option<&HashMap<String, Option<I18nMember>>>,
+  ) {
+    let symbol_id = local.symbol_id();
+
+    let imported_member =
+      members.and_then(|members| Some((members, members.get(imported_name)?.as_ref()?)));
+
+    if let Some((members, member)) = imported_member {
+      self.current_type = member.r#type.clone();
+      match member.r#type {
+        I18nType::Hook => {
+          self.with_key_prefix(member.key_prefix.clone(), |walker| {
+            walker.read_hook(symbol_id, imported_name, member.ns.clone(), members);
+          });
+        }
+        I18nType::FixedT => {
+          self.register_t_symbol(symbol_id, local.name.as_str());
+          self.with_key_prefix(member.key_prefix.clone(), |walker| {
+            walker.read_t(symbol_id, member.ns.clone());
+          });
+        }
+        I18nType::TMethod => {
+          self.register_t_symbol(symbol_id, local.name.as_str());
+          self.with_t_arguments(member.arguments.clone(), |walker| {
+            walker.read_t(symbol_id, member.ns.clone());
+          });
+        }
+        I18nType::ObjectMemberT => {
+          let translation_names = Walker::collect_t_member_names(members);
+          self.register_translation_names(translation_names);
+          let key_methods = member
+            .key_methods
+            .clone()
+            .unwrap_or_else(preset_key_methods);
+          self.read_object_member_t(symbol_id, member.ns.clone(), &key_methods);
+        }
+        I18nType::TransComp => {
+          self.read_trans_component(symbol_id, member.ns.clone());
+        }
+        I18nType::TranslationComp => {
+          self.read_translation_component(symbol_id, member.ns.clone());
+        }
+        I18nType::HocWrapper => {
+          self.read_hoc_wrapper(symbol_id, member.ns.clone());
+        }
       }
+    } else if self.is_standard_hook_export(imported_name) {
+      self.current_type = I18nType::Hook;
+      let empty_members: HashMap<String, Option<I18nMember>> = HashMap::new();
+      let translation_names = Walker::collect_t_member_names(&empty_members);
+      self.register_translation_names(translation_names.clone());
+      self.read_hook(symbol_id, imported_name, None, &empty_members);
+    } else if imported_name == "t" {
+      self.current_type = I18nType::TMethod;
+      self.register_t_symbol(symbol_id, local.name.as_str());
+      self.read_t(symbol_id, None);
     }
   }
 
-  // fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
-  //   todo!()
-  // }
+  /// Reads the usages of the default export, the i18n instance of i18next
+  fn read_default_binding(
+    &mut self,
+    local: &BindingIdentifier,
+    members: &HashMap<String, Option<I18nMember>>,
+  ) {
+    if let Some(member) = default_import_member(members) {
+      self.current_type = I18nType::ObjectMemberT;
+      let translation_names = Walker::collect_t_member_names(members);
+      self.register_translation_names(translation_names);
+      let key_methods = member
+        .key_methods
+        .clone()
+        .unwrap_or_else(preset_key_methods);
+      self.read_object_member_t(local.symbol_id(), member.ns.clone(), &key_methods);
+    }
+  }
 }
@@ -1,43 +1,84 @@
-use crate::analyzer::i18n_packages::{is_preset_member_name, preset_member_names};
-use crate::collector::post_collector::PostCollector;
-use crate::node::i18n_types::{I18nMember, I18nType};
+use crate::analyzer::i18n_packages::{
+  is_preset_member_name, preset_key_methods, preset_member_names, FIXED_T_METHOD,
+};
+use crate::collector::key_detail::{KeyLocation, LineIndex};
+use crate::collector::post_collector::{PendingIdentifierKey, PostCollector};
+use crate::collector::trans_children::serialize_trans_children;
+use crate::key_config::KeyConfig;
+use crate::module_cache::ModuleCache;
+use crate::node::i18n_types::{I18nMember, I18nType, KeyMethod, TArguments};
 use crate::node::node::Node;
 use crate::walk_utils::WalkerUtils;
 use log::debug;
-use oxc_allocator::Box as OxcBox;
 use oxc_ast::ast::{
   ArrayPattern, BinaryExpression, BinaryOperator, BindingPattern, CallExpression, Expression,
-  IdentifierReference, ImportSpecifier, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
-  JSXChild, JSXElement, JSXExpression, JSXFragment, JSXOpeningElement, ObjectPropertyKind,
-  PropertyKey, SourceType, Statement, VariableDeclarator,
+  IdentifierReference, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild, JSXElement,
+  JSXExpression, JSXFragment, JSXOpeningElement, ObjectPropertyKind, PropertyKey, ReturnStatement,
+  Span, Statement, VariableDeclarator,
 };
 use oxc_ast::AstKind;
+use oxc_ast_visit::{walk, Visit};
 use oxc_semantic::{AstNode, Semantic};
 use oxc_syntax::node::NodeId;
 use oxc_syntax::reference::ReferenceId;
 use oxc_syntax::symbol::SymbolId;
 use std::collections::{HashMap, HashSet};
-use std::rc::Rc;
+use std::sync::Arc;
+
+/// The options of a t call that change which keys it reads
+struct TOptions {
+  ns: String,
+  has_count: bool,
+  context: Option<String>,
+  default_value: Option<String>,
+}
 
 pub struct Walker<'a> {
-  pub node: Rc<Node>,
+  pub node: Arc<Node>,
   pub semantic: &'a Semantic<'a>,
   pub i18n_namespaces: HashMap<String, Vec<String>>,
+  // { namespace: { key: locations } }
+  pub i18n_key_locations: HashMap<String, HashMap<String, Vec<KeyLocation>>>,
+  // { namespace: { key: default value } }
+  pub i18n_default_values: HashMap<String, HashMap<String, String>>,
   pub post_collects: PostCollector,
   pub walk_utils: WalkerUtils<'a>,
+  pub key_config: KeyConfig,
+  // The `keyPrefix` of the hook whose `t` is being read
+  key_prefix: Option<String>,
+  // Argument positions of the translator whose calls are being read
+  t_arguments: TArguments,
+  // Methods reading keys of the i18n instance returned by the hook being read
+  key_methods: Vec<KeyMethod>,
+  // The i18n member type currently being read, attached to every collected location
+  pub current_type: I18nType,
+  line_index: LineIndex<'a>,
   t_symbol_ids: HashSet<SymbolId>,
   t_function_names: HashSet<String>,
   translation_member_names: HashSet<String>,
 }
 
 impl<'a> Walker<'a> {
-  pub fn new(node: Rc<Node>, semantic: &'a Semantic<'a>) -> Self {
+  pub fn new(
+    node: Arc<Node>,
+    semantic: &'a Semantic<'a>,
+    module_cache: ModuleCache,
+    key_config: KeyConfig,
+  ) -> Self {
     Self {
       node: node.clone(),
       semantic,
       i18n_namespaces: HashMap::new(),
+      i18n_key_locations: HashMap::new(),
+      i18n_default_values: HashMap::new(),
       post_collects: PostCollector::new(),
-      walk_utils: WalkerUtils::new(semantic, node.clone()),
+      walk_utils: WalkerUtils::new(semantic, node.clone(), module_cache),
+      key_config,
+      key_prefix: None,
+      t_arguments: TArguments::default(),
+      key_methods: preset_key_methods(),
+      current_type: I18nType::TMethod,
+      line_index: LineIndex::new(semantic.source_text()),
       t_symbol_ids: HashSet::new(),
       t_function_names: HashSet::new(),
       translation_member_names: HashSet::new(),
@@ -131,7 +172,21 @@ impl<'a> Walker<'a> {
       })
   }
 
-  pub fn read_object_member_t(&mut self, symbol_id: SymbolId, defined_ns: Option<String>) {
+  /// Reads the calls of a translator used without being declared, `$t` of Vue components
+  pub fn read_global_t(&mut self, name: &str) {
+    let previous_type = std::mem::replace(&mut self.current_type, I18nType::TMethod);
+    for call in self.walk_utils.global_t_calls(name) {
+      self.read_t_arguments(call, None);
+    }
+    self.current_type = previous_type;
+  }
+
+  pub fn read_object_member_t(
+    &mut self,
+    symbol_id: SymbolId,
+    defined_ns: Option<String>,
+    key_methods: &[KeyMethod],
+  ) {
     let namespace = defined_ns.clone();
 
     self
@@ -146,6 +201,26 @@ impl<'a> Walker<'a> {
               if let AstKind::CallExpression(call) = call_node.kind() {
                 self.read_t_arguments(call, namespace.clone());
               }
+            } else if let Some(method) = key_methods
+              .iter()
+              .find(|method| method.name == prop.as_str())
+            {
+              // i18n.exists('key'), i18n.getResource(lng, 'ns', 'key')
+              let call_node = self.semantic.nodes().parent_node(node.id());
+              if let AstKind::CallExpression(call) = call_node.kind() {
+                self.read_key_arguments(
+                  call,
+                  method.key_index as usize,
+                  method.ns_index.map(|ns_index| ns_index as usize),
+                  None,
+                  namespace.clone(),
+                );
+              }
+            } else if prop.as_str() == FIXED_T_METHOD {
+              let call_node = self.semantic.nodes().parent_node(node.id());
+              if let AstKind::CallExpression(call) = call_node.kind() {
+                self.read_fixed_t(call_node, call, namespace.clone());
+              }
             }
           });
         } else {
@@ -179,17 +254,49 @@ impl<'a> Walker<'a> {
       })
   }
 
+  /// const t = i18n.getFixedT(null, 'ns', 'keyPrefix');
+  fn read_fixed_t(
+    &mut self,
+    call_node: &AstNode,
+    call: &CallExpression,
+    defined_ns: Option<String>,
+  ) {
+    let var_node = self.semantic.nodes().parent_node(call_node.id());
+    let AstKind::VariableDeclarator(var) = var_node.kind() else {
+      return;
+    };
+    let BindingPattern::BindingIdentifier(ident) = &var.id else {
+      return;
+    };
+
+    let (namespace, key_prefix) = self.walk_utils.read_fixed_t_arguments(call);
+    let symbol_id = ident.symbol_id();
+    let previous_type = std::mem::replace(&mut self.current_type, I18nType::FixedT);
+    self.register_t_symbol(symbol_id, ident.name.as_str());
+    self.with_key_prefix(key_prefix, |walker| {
+      walker.read_t(symbol_id, namespace.or(defined_ns));
+    });
+    self.current_type = previous_type;
+  }
+
   pub fn read_hook(
     &mut self,
-    s: &OxcBox<ImportSpecifier>,
+    local_symbol_id: SymbolId,
+    imported_name: &str,
     defined_ns: Option<String>,
     members: &HashMap<String, Option<I18nMember>>,
   ) {
-    let local_symbol_id = s.local.symbol_id();
-
     let translation_names = Self::collect_t_member_names(members);
     self.register_translation_names(translation_names.iter().cloned());
-    let is_standard_hook = self.is_standard_hook_export(s.imported.name().as_str());
+    let is_standard_hook = self.is_standard_hook_export(imported_name);
+    // const { i18n } = useTranslation() is the `ObjectMemberT` member of the package
+    let key_methods = members
+      .values()
+      .flatten()
+      .find(|member| matches!(member.r#type, I18nType::ObjectMemberT))
+      .and_then(|member| member.key_methods.clone())
+      .unwrap_or_else(preset_key_methods);
+    let previous = std::mem::replace(&mut self.key_methods, key_methods);
     let mut visited_symbols = HashSet::new();
     self.process_hook_symbol_references(
       local_symbol_id,
@@ -197,13 +304,42 @@ impl<'a> Walker<'a> {
       is_standard_hook,
       &mut visited_symbols,
     );
+    self.key_methods = previous;
   }
 
   pub fn read_t_arguments(&mut self, call: &CallExpression, namespace: Option<String>) {
-    let Some(arg) = call.arguments.get(0) else {
+    let TArguments { key, ns, options } = self.t_arguments.clone();
+    self.read_key_arguments(call, key, ns, options, namespace);
+  }
+
+  /// Reads the key at `key_index` like a t call, the namespace is the argument at `ns_index`
+  /// or read from the options, which follow the key unless `options_index` is given
+  pub fn read_key_arguments(
+    &mut self,
+    call: &CallExpression,
+    key_index: usize,
+    ns_index: Option<usize>,
+    options_index: Option<usize>,
+    namespace: Option<String>,
+  ) {
+    let Some(arg) = call.arguments.get(key_index) else {
       return;
     };
-    let ns = self.resolve_namespace(call, namespace);
+    let namespace = match ns_index {
+      Some(ns_index) => call
+        .arguments
+        .get(ns_index)
+        .and_then(|arg| arg.as_expression())
+        .and_then(|expr| self.walk_utils.read_str_expression(expr))
+        .or(namespace),
+      None => namespace,
+    };
+    let options = self.read_t_options(call, options_index.unwrap_or(key_index + 1), namespace);
+    let ns = options.ns;
+    let default_value = options.default_value;
+    let suffixes = self
+      .key_config
+      .key_suffixes(options.has_count, options.context.as_deref());
 
     let Some(expr) = arg.as_expression() else {
       return;
@@ -212,56 +348,112 @@ impl<'a> Walker<'a> {
     if let Some(key) = self.walk_utils.read_str_expression(expr) {
       // Add the key directly without any hardcoded pattern matching
       debug!("Adding key: '{}' to namespace: '{}'", key, ns);
-      self.add_key(&ns, key);
+      let key = self.prefix_key(key);
+      for suffix in &suffixes {
+        self.add_key_with_default(
+          &ns,
+          format!("{key}{suffix}"),
+          default_value.clone(),
+          call.span,
+        );
+      }
       return;
     }
 
     if let Expression::Identifier(ident) = expr {
       // Defer unresolved identifier keys to post-collection so we can resolve
       // cross-file alias/import-export chains after the main AST traversal.
-      self.post_collects.add_pending_identifier_key(
-        self.node.file_path.to_string(),
-        ns.clone(),
-        ident.name.to_string(),
-      );
+      self
+        .post_collects
+        .add_pending_identifier_key(PendingIdentifierKey {
+          node_path: self.node.file_path.to_string(),
+          namespace: ns.clone(),
+          identifier: ident.name.to_string(),
+          suffixes: suffixes.clone(),
+          key_prefix: self.key_prefix.clone(),
+          default_value: default_value.clone(),
+          location: self.make_location(call.span),
+        });
     }
 
     // If we can't resolve the key, check if this is a dynamic key pattern
     if let Some(dynamic_keys) = self.try_resolve_dynamic_keys(expr) {
       for dynamic_key in dynamic_keys {
-        self.add_key(&ns, dynamic_key);
+        let dynamic_key = self.prefix_key(dynamic_key);
+        for suffix in &suffixes {
+          self.add_key_with_default(
+            &ns,
+            format!("{dynamic_key}{suffix}"),
+            default_value.clone(),
+            call.span,
+          );
+        }
       }
     }
   }
 
-  fn resolve_namespace(&self, call: &CallExpression, namespace: Option<String>) -> String {
-    call
+  fn prefix_key(&self, key: String) -> String {
+    match &self.key_prefix {
+      Some(key_prefix) => self.key_config.prefix_key(key_prefix, &key),
+      None => key,
+    }
+  }
+
+  /// Reads the options of a t call from `options_index`, either t("abc", { ns: "xyz", count, context: "male" })
+  /// or t("abc", "Default value", { ns: "xyz" })
+  fn read_t_options(
+    &self,
+    call: &CallExpression,
+    options_index: usize,
+    namespace: Option<String>,
+  ) -> TOptions {
+    let mut ns = None;
+    let mut has_count = false;
+    let mut context = None;
+    let mut default_value = None;
+
+    let mut arguments = call
       .arguments
-      .get(1)
-      .and_then(|arg| arg.as_expression())
-      .and_then(|expr| {
-        // t("abc", { ns: "xyz" })
-        if let Expression::ObjectExpression(obj) = expr {
-          obj.properties.iter().find_map(|prop| {
-            let ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
-              return None;
-            };
-
-            let PropertyKey::StaticIdentifier(prop_name) = &obj_prop.key else {
-              return None;
-            };
-
-            if prop_name.name == "ns" {
-              return self.walk_utils.read_str_expression(&obj_prop.value);
-            }
-            None
-          })
-        } else {
-          None
+      .iter()
+      .skip(options_index)
+      .take(2)
+      .filter_map(|arg| arg.as_expression());
+    let mut options = arguments.next();
+    if let Some(expr) = options {
+      if !matches!(expr.get_inner_expression(), Expression::ObjectExpression(_)) {
+        default_value = self.walk_utils.read_str_expression(expr);
+        options = arguments.next();
+      }
+    }
+
+    if let Some(Expression::ObjectExpression(obj)) = options.map(|expr| expr.get_inner_expression())
+    {
+      for prop in &obj.properties {
+        let ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
+          continue;
+        };
+
+        let PropertyKey::StaticIdentifier(prop_name) = &obj_prop.key else {
+          continue;
+        };
+
+        match prop_name.name.as_str() {
+          "ns" => ns = self.walk_utils.read_str_expression(&obj_prop.value),
+          "count" => has_count = true,
+          // A context only known at runtime keeps the base key alone
+          "context" => context = self.walk_utils.read_str_expression(&obj_prop.value),
+          "defaultValue" => default_value = self.walk_utils.read_str_expression(&obj_prop.value),
+          _ => {}
         }
-      })
-      .or(namespace)
-      .unwrap_or_else(|| "default".to_string())
+      }
+    }
+
+    TOptions {
+      ns: ns.or(namespace).unwrap_or_else(|| "default".to_string()),
+      has_count,
+      context,
+      default_value,
+    }
   }
 
   pub fn read_namespace_import(
@@ -287,6 +479,7 @@ impl<'a> Walker<'a> {
                 "Found member info for {}: {:?}",
                 prop_name, member_info.r#type
               );
+              self.current_type = member_info.r#type.clone();
               match member_info.r#type {
                 I18nType::Hook => {
                   // Handle i18n.useTranslation()
@@ -305,7 +498,10 @@ impl<'a> Walker<'a> {
                       self.register_translation_names(translation_names.iter().cloned());
 
                       let namespace_ref = &namespace;
-                      self.process_binding_pattern(&var.id, namespace_ref, false);
+                      let key_prefix = self.walk_utils.read_hook_key_prefix(call);
+                      self.with_key_prefix(key_prefix, |walker| {
+                        walker.process_binding_pattern(&var.id, namespace_ref, false);
+                      });
                     }
                   }
                 }
@@ -314,7 +510,9 @@ impl<'a> Walker<'a> {
                   debug!("Processing t method call: {}", prop_name);
                   let call_node = self.semantic.nodes().parent_node(node.id());
                   if let AstKind::CallExpression(call) = call_node.kind() {
-                    self.read_t_arguments(call, member_info.ns.clone());
+                    self.with_t_arguments(member_info.arguments.clone(), |walker| {
+                      walker.read_t_arguments(call, member_info.ns.clone());
+                    });
                   }
                 }
                 _ => {}
@@ -564,9 +762,15 @@ impl<'a> Walker<'a> {
             AstKind::JSXElement(jsx_element) => {
               self.read_trans_jsx_element(jsx_element, defined_ns.clone());
             }
-            // Handle JSX opening elements like <Trans i18nKey="key">
+            // Handle JSX opening elements like <Trans i18nKey="key">, the children are on the parent element
             AstKind::JSXOpeningElement(opening_element) => {
-              self.read_trans_jsx_opening_element(opening_element, defined_ns.clone());
+              let parent_node = self.semantic.nodes().parent_node(node.id());
+              match parent_node.kind() {
+                AstKind::JSXElement(jsx_element) => {
+                  self.read_trans_jsx_element(jsx_element, defined_ns.clone());
+                }
+                _ => self.read_trans_jsx_opening_element(opening_element, &[], defined_ns.clone()),
+              }
             }
             _ => {}
           }
@@ -576,14 +780,49 @@ impl<'a> Walker<'a> {
 
   pub fn read_trans_jsx_element(&mut self, jsx_element: &JSXElement, defined_ns: Option<String>) {
     let opening_element = &jsx_element.opening_element;
-    self.read_trans_jsx_opening_element(opening_element, defined_ns);
+    self.read_trans_jsx_opening_element(opening_element, &jsx_element.children, defined_ns);
   }
 
   pub fn read_trans_jsx_opening_element(
     &mut self,
     opening_element: &JSXOpeningElement,
+    children: &[JSXChild],
     defined_ns: Option<String>,
   ) {
+    // <Trans i18nKey="key" defaults="Hello <0>world</0>" /> or the serialized children
+    let defaults = self.read_jsx_string_attribute(opening_element, "defaults");
+    let serialized = serialize_trans_children(
+      children,
+      &self.key_config.trans_keep_basic_html_nodes_for,
+      &self.walk_utils,
+    );
+    let serialized = (!serialized.is_empty()).then_some(serialized);
+    let default_value = defaults.clone().or_else(|| serialized.clone());
+
+    // <Trans count={n} context="male"> reads the plural and context keys like a t call
+    let suffixes = self.key_config.key_suffixes(
+      Self::has_jsx_attribute(opening_element, "count"),
+      self
+        .read_jsx_string_attribute(opening_element, "context")
+        .as_deref(),
+    );
+
+    // <Trans>Hello <strong>{{name}}</strong></Trans> is keyed by its serialized children
+    if !Self::has_jsx_attribute(opening_element, "i18nKey") {
+      if let Some(key) = serialized.or(defaults) {
+        let namespace = self.resolve_jsx_namespace(opening_element, defined_ns);
+        for suffix in &suffixes {
+          self.add_key_with_default(
+            &namespace,
+            format!("{key}{suffix}"),
+            default_value.clone(),
+            opening_element.span,
+          );
+        }
+      }
+      return;
+    }
+
     // Look for i18nKey prop
     for attribute in &opening_element.attributes {
       if let JSXAttributeItem::Attribute(attr) = attribute {
@@ -593,7 +832,14 @@ impl<'a> Walker<'a> {
             if let Some(attr_value) = &attr.value {
               if let JSXAttributeValue::StringLiteral(s) = attr_value {
                 let namespace = self.resolve_jsx_namespace(opening_element, defined_ns.clone());
-                self.add_key(&namespace, s.value.to_string());
+                for suffix in &suffixes {
+                  self.add_key_with_default(
+                    &namespace,
+                    format!("{}{suffix}", s.value),
+                    default_value.clone(),
+                    attr.span,
+                  );
+                }
               }
             }
           }
@@ -602,6 +848,42 @@ impl<'a> Walker<'a> {
     }
   }
 
+  fn has_jsx_attribute(opening_element: &JSXOpeningElement, name: &str) -> bool {
+    opening_element.attributes.iter().any(|attribute| {
+      matches!(
+        attribute,
+        JSXAttributeItem::Attribute(attr)
+          if matches!(&attr.name, JSXAttributeName::Identifier(ident) if ident.name == name)
+      )
+    })
+  }
+
+  fn read_jsx_string_attribute(
+    &self,
+    opening_element: &JSXOpeningElement,
+    name: &str,
+  ) -> Option<String> {
+    opening_element.attributes.iter().find_map(|attribute| {
+      let JSXAttributeItem::Attribute(attr) = attribute else {
+        return None;
+      };
+      let JSXAttributeName::Identifier(ident) = &attr.name else {
+        return None;
+      };
+      if ident.name != name {
+        return None;
+      }
+
+      match attr.value.as_ref()? {
+        JSXAttributeValue::StringLiteral(s) => Some(s.value.to_string()),
+        JSXAttributeValue::ExpressionContainer(container) => self
+          .walk_utils
+          .read_str_expression(container.expression.as_expression()?),
+        _ => None,
+      }
+    })
+  }
+
   pub fn read_translation_component(&mut self, symbol_id: SymbolId, defined_ns: Option<String>) {
     self
       .semantic
@@ -881,8 +1163,54 @@ impl<'a> Walker<'a> {
     }
   }
 
-  pub fn add_key(&mut self, namespace: &str, key: String) {
+  pub fn make_location(&self, span: Span) -> KeyLocation {
+    let (line, column) = self.line_index.line_column(span.start);
+
+    KeyLocation {
+      file_path: self.node.file_path.to_string(),
+      line,
+      column,
+      r#type: self.current_type.clone(),
+    }
+  }
+
+  pub fn add_key(&mut self, namespace: &str, key: String, span: Span) {
+    self.add_key_with_default(namespace, key, None, span);
+  }
+
+  /// Same as `add_key` but remembers the default value the key was used with,
+  /// the first default value read for a key wins
+  pub fn add_key_with_default(
+    &mut self,
+    namespace: &str,
+    key: String,
+    default_value: Option<String>,
+    span: Span,
+  ) {
     debug!("add_key called: namespace='{}', key='{}'", namespace, key);
+    // t('common:save') reads the key `save` of the `common` namespace
+    let (namespace, key) = match self.key_config.split_namespace(&key) {
+      Some((namespace, key)) => (namespace.to_string(), key),
+      None => (namespace.to_string(), key),
+    };
+    let namespace = namespace.as_str();
+    if let Some(default_value) = default_value {
+      self
+        .i18n_default_values
+        .entry(namespace.to_string())
+        .or_default()
+        .entry(key.clone())
+        .or_insert(default_value);
+    }
+    let location = self.make_location(span);
+    self
+      .i18n_key_locations
+      .entry(namespace.to_string())
+      .or_default()
+      .entry(key.clone())
+      .or_default()
+      .push(location);
+
     let keys = self
       .i18n_namespaces
       .entry(namespace.to_string())
@@ -938,7 +1266,7 @@ impl<'a> Walker<'a> {
           if let Some(transformed_key) = self.resolve_custom_hook_transformation(&input_key) {
             debug!("Transformed key: {}", transformed_key);
             let namespace = defined_ns.unwrap_or_else(|| "default".to_string());
-            self.add_key(&namespace, transformed_key);
+            self.add_key(&namespace, transformed_key, call.span);
           } else {
             debug!("Failed to transform key: {}", input_key);
           }
@@ -981,11 +1309,36 @@ impl<'a> Walker<'a> {
             .walk_utils
             .read_hook_namespace_argument(call)
             .or_else(|| defined_ns.clone());
-          self.handle_hook_call_context(node, namespace, visited_symbols);
+          // A wrapper hook keeps the prefix of the hook it returns
+          let key_prefix = self
+            .walk_utils
+            .read_hook_key_prefix(call)
+            .or_else(|| self.key_prefix.clone());
+          self.with_key_prefix(key_prefix, |walker| {
+            walker.handle_hook_call_context(node, namespace, visited_symbols);
+          });
         }
       });
   }
 
+  /// Reads the calls of a translator with its own argument positions
+  pub(crate) fn with_t_arguments(&mut self, t_arguments: TArguments, read: impl FnOnce(&mut Self)) {
+    let previous = std::mem::replace(&mut self.t_arguments, t_arguments);
+    read(self);
+    self.t_arguments = previous;
+  }
+
+  /// Reads the `t` of a hook with its `keyPrefix`, the previous prefix is restored afterwards
+  pub(crate) fn with_key_prefix(
+    &mut self,
+    key_prefix: Option<String>,
+    read: impl FnOnce(&mut Self),
+  ) {
+    let previous = std::mem::replace(&mut self.key_prefix, key_prefix);
+    read(self);
+    self.key_prefix = previous;
+  }
+
   fn handle_hook_call_context(
     &mut self,
     call_node: &AstNode,
@@ -1081,7 +1434,8 @@ impl<'a> Walker<'a> {
           self.read_t(ident.symbol_id(), namespace.clone());
         } else {
           // Track plain identifiers for later destructuring or member usage.
-          self.read_object_member_t(ident.symbol_id(), namespace.clone());
+          let key_methods = self.key_methods.clone();
+          self.read_object_member_t(ident.symbol_id(), namespace.clone(), &key_methods);
         }
       }
       BindingPattern::AssignmentPattern(assign) => {
@@ -1098,7 +1452,14 @@ impl<'a> Walker<'a> {
             }
           }
 
-          self.process_binding_pattern(&prop.value, namespace, should_force);
+          if should_force {
+            self.process_binding_pattern(&prop.value, namespace, true);
+          } else {
+            // const { i18n } = useTranslation('ns', { keyPrefix }), the instance ignores the prefix
+            self.with_key_prefix(None, |walker| {
+              walker.process_binding_pattern(&prop.value, namespace, false);
+            });
+          }
         }
 
         if let Some(rest) = &obj.rest {
@@ -1244,43 +1605,17 @@ impl<'a> Walker<'a> {
   }
 
   fn analyze_file_for_hook_pattern(&self, file_path: &str) -> Option<String> {
-    // Parse the given file and look for hook transformation patterns
-    use oxc_allocator::Allocator;
-    use oxc_parser::Parser;
-    use oxc_semantic::SemanticBuilder;
-    use std::fs;
-
-    // Read the file content
-    let source_text = fs::read_to_string(file_path).ok()?;
-
-    // Parse the file
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path(file_path).unwrap_or_default();
-    let parser = Parser::new(&allocator, &source_text, source_type);
-    let program = parser.parse().program;
-    let semantic = SemanticBuilder::new().build(&program);
-
-    // Look for return statements with template literals in the parsed file
-    for node in semantic.semantic.nodes().iter() {
-      if let AstKind::ReturnStatement(ret_stmt) = node.kind() {
-        if let Some(arg) = &ret_stmt.argument {
-          if let Expression::CallExpression(call) = arg {
-            if let Expression::Identifier(ident) = &call.callee {
-              if self.is_known_t_name(ident.name.as_str()) {
-                // Found a t() call in return statement
-                if let Some(first_arg) = call.arguments.get(0) {
-                  if let Some(expr) = first_arg.as_expression() {
-                    return self.extract_template_pattern(expr);
-                  }
-                }
-              }
-            }
-          }
-        }
-      }
-    }
-
-    None
+    // The AST is shared with the other phases, so walk it directly
+    // instead of rebuilding a semantic for the hook file
+    let module = self.walk_utils.module_cache.get(file_path)?;
+    let mut finder = ReturnedTemplateCallFinder::default();
+    finder.visit_program(module.program());
+
+    // Found a t() call in return statement
+    finder
+      .calls
+      .into_iter()
+      .find_map(|(callee, pattern)| self.is_known_t_name(&callee).then_some(pattern))
   }
 
   fn analyze_current_hook_implementation(&self) -> Option<String> {
@@ -1299,7 +1634,7 @@ impl<'a> Walker<'a> {
                 // Found a t() call in return statement
                 if let Some(first_arg) = call.arguments.get(0) {
                   if let Some(expr) = first_arg.as_expression() {
-                    return self.extract_template_pattern(expr);
+                    return Self::extract_template_pattern(expr);
                   }
                 }
               }
@@ -1312,7 +1647,7 @@ impl<'a> Walker<'a> {
     None
   }
 
-  fn extract_template_pattern(&self, expr: &Expression) -> Option<String> {
+  fn extract_template_pattern(expr: &Expression) -> Option<String> {
     match expr {
       Expression::TemplateLiteral(tpl) => {
         // Extract the template pattern from a template literal
@@ -1339,3 +1674,28 @@ impl<'a> Walker<'a> {
     None
   }
 }
+
+/// Collects `return callee(`PREFIX_${key}`)` as (callee, template pattern) pairs
+#[derive(Default)]
+struct ReturnedTemplateCallFinder {
+  calls: Vec<(String, String)>,
+}
+
+impl<'a> Visit<'a> for ReturnedTemplateCallFinder {
+  fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+    if let Some(Expression::CallExpression(call)) = &it.argument {
+      if let Expression::Identifier(ident) = &call.callee {
+        let pattern = call
+          .arguments
+          .first()
+          .and_then(|arg| arg.as_expression())
+          .and_then(Walker::extract_template_pattern);
+
+        if let Some(pattern) = pattern {
+          self.calls.push((ident.name.to_string(), pattern));
+        }
+      }
+    }
+    walk::walk_return_statement(self, it);
+  }
+}
@@ -0,0 +1,657 @@
+use crate::analyzer::i18n_packages::{I18nPackage, Member};
+use crate::node::i18n_types::{I18nType, KeyMethod};
+use crate::resources::locale_resources::ResourceOptions;
+use crate::resources::resource_writer::WriteOptions;
+use crate::template_extractor::TemplateExtractor;
+use crate::Payload;
+use napi::Either;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// A field of the config holding an unexpected value, `field` is its path, e.g. `extendI18NPackages[0].members`
+#[derive(Debug, PartialEq)]
+pub struct ConfigError {
+  pub field: String,
+  pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if self.field.is_empty() {
+      write!(f, "{}", self.message)
+    } else {
+      write!(f, "`{}` {}", self.field, self.message)
+    }
+  }
+}
+
+/// Names of the config file, looked up from the working directory upward
+pub const CONFIG_FILE_NAMES: &[&str] = &["i18n-scanner.config.json", "i18n-scanner.config.jsonc"];
+
+const PAYLOAD_FIELDS: &[&str] = &[
+  "tsconfigPath",
+  "entryPaths",
+  "include",
+  "exclude",
+  "externals",
+  "extendI18NPackages",
+  "threads",
+  "keySeparator",
+  "nsSeparator",
+  "namespaces",
+  "languages",
+  "transKeepBasicHtmlNodesFor",
+  "templateExtractors",
+  "failOnParseError",
+];
+
+/// Options of the config file, shared by the JS API and the command line
+#[napi(object)]
+pub struct ScanConfig {
+  /// The file the options were read from
+  pub config_path: String,
+  pub payload: Payload,
+  /// Locale resources of `findUnusedKeys`, `findMissingKeys` and `writeResources`
+  pub resources: Option<ResourceOptions>,
+  /// Options of `writeResources`
+  pub write: Option<WriteOptions>,
+  /// What the command line prints
+  pub output: Option<OutputOptions>,
+}
+
+#[napi(object)]
+pub struct OutputOptions {
+  /// `keys`, `detailed` or `nested`, the result of `scan`, `scanDetailed` or `scanNested`
+  pub format: Option<String>,
+  /// Writes the result to the file instead of stdout
+  pub file: Option<String>,
+}
+
+/// Finds the closest config file from the directory upward
+pub fn find_config(from: &Path) -> Option<PathBuf> {
+  from.ancestors().find_map(|dir| {
+    CONFIG_FILE_NAMES
+      .iter()
+      .map(|name| dir.join(name))
+      .find(|config_path| config_path.is_file())
+  })
+}
+
+/// Loads the config file at `config_path`, or the closest one from the working directory
+pub fn load_config(config_path: Option<&str>) -> Result<ScanConfig, ConfigError> {
+  let cwd = env::current_dir().map_err(|err| ConfigError {
+    field: String::new(),
+    message: format!("Unable to read the working directory: {err}"),
+  })?;
+  let config_path = match config_path {
+    Some(config_path) => cwd.join(config_path),
+    None => find_config(&cwd).ok_or_else(|| ConfigError {
+      field: String::new(),
+      message: format!("no {} found from {}", CONFIG_FILE_NAMES[0], cwd.display()),
+    })?,
+  };
+
+  let content = fs::read_to_string(&config_path).map_err(|err| ConfigError {
+    field: String::new(),
+    message: format!("Unable to read {}: {err}", config_path.display()),
+  })?;
+  let config =
+    serde_json::from_str::<Value>(&strip_jsonc(&content)).map_err(|err| ConfigError {
+      field: String::new(),
+      message: format!("Invalid JSON in {}: {err}", config_path.display()),
+    })?;
+
+  read_config(&config, &config_path).map_err(|err| ConfigError {
+    message: format!("{} in {}", err.message, config_path.display()),
+    ..err
+  })
+}
+
+/// Reads the config, relative paths are resolved from the directory of the config file
+pub fn read_config(config: &Value, config_path: &Path) -> Result<ScanConfig, ConfigError> {
+  let config_dir = config_path.parent().unwrap_or(Path::new(""));
+  let resolve = |path: String| {
+    if path.is_empty() || Path::new(&path).is_absolute() {
+      path
+    } else {
+      config_dir.join(path).display().to_string()
+    }
+  };
+
+  let fields = Fields::new(config, String::new())?;
+  fields.deny_unknown(&[PAYLOAD_FIELDS, &["resources", "write", "output"]].concat())?;
+
+  let mut payload = read_payload_fields(&fields)?;
+  payload.tsconfig_path = resolve(payload.tsconfig_path);
+  payload.entry_paths = payload.entry_paths.into_iter().map(resolve).collect();
+  payload.include = payload
+    .include
+    .map(|include| include.into_iter().map(resolve).collect());
+  // `**/*.test.ts` matches anywhere, only the patterns naming a path are resolved
+  payload.exclude = payload.exclude.map(|exclude| {
+    exclude
+      .into_iter()
+      .map(|pattern| {
+        if pattern.starts_with("**") {
+          pattern
+        } else {
+          resolve(pattern)
+        }
+      })
+      .collect()
+  });
+  for extractor in payload.template_extractors.iter_mut().flatten() {
+    extractor.files = extractor.files.drain(..).map(resolve).collect();
+  }
+
+  let mut resources = fields
+    .object("resources")?
+    .map(|fields| read_resources(&fields))
+    .transpose()?;
+  if let Some(resources) = &mut resources {
+    resources.path_pattern = resolve(resources.path_pattern.clone());
+  }
+  let mut output = fields
+    .object("output")?
+    .map(|fields| read_output(&fields))
+    .transpose()?;
+  if let Some(output) = &mut output {
+    output.file = output.file.take().map(resolve);
+  }
+
+  Ok(ScanConfig {
+    config_path: config_path.display().to_string(),
+    payload,
+    resources,
+    write: fields
+      .object("write")?
+      .map(|fields| read_write_options(&fields))
+      .transpose()?,
+    output,
+  })
+}
+
+/// Reads a scan payload out of JSON, the fields are named like the `Payload` of the JS API
+pub fn read_payload(config: &Value) -> Result<Payload, ConfigError> {
+  let fields = Fields::new(config, String::new())?;
+  fields.deny_unknown(PAYLOAD_FIELDS)?;
+
+  read_payload_fields(&fields)
+}
+
+fn read_payload_fields(fields: &Fields) -> Result<Payload, ConfigError> {
+  Ok(Payload {
+    tsconfig_path: fields.string("tsconfigPath")?.unwrap_or_default(),
+    entry_paths: fields.strings("entryPaths")?.unwrap_or_default(),
+    include: fields.strings("include")?,
+    exclude: fields.strings("exclude")?,
+    externals: fields.strings("externals")?.unwrap_or_default(),
+    extend_i18n_packages: fields
+      .objects("extendI18NPackages")?
+      .map(|packages| packages.iter().map(read_package).collect())
+      .transpose()?,
+    threads: fields.index("threads")?,
+    key_separator: fields.separator("keySeparator")?,
+    ns_separator: fields.separator("nsSeparator")?,
+    namespaces: fields.strings("namespaces")?,
+    languages: fields.strings("languages")?,
+    trans_keep_basic_html_nodes_for: fields.strings("transKeepBasicHtmlNodesFor")?,
+    template_extractors: fields
+      .objects("templateExtractors")?
+      .map(|extractors| extractors.iter().map(read_template_extractor).collect())
+      .transpose()?,
+    fail_on_parse_error: fields.bool("failOnParseError")?,
+  })
+}
+
+fn read_resources(fields: &Fields) -> Result<ResourceOptions, ConfigError> {
+  fields.deny_unknown(&["pathPattern", "languages", "defaultNamespace"])?;
+
+  Ok(ResourceOptions {
+    path_pattern: fields.required(fields.string("pathPattern"), "pathPattern")?,
+    languages: fields.strings("languages")?,
+    default_namespace: fields.string("defaultNamespace")?,
+  })
+}
+
+fn read_write_options(fields: &Fields) -> Result<WriteOptions, ConfigError> {
+  fields.deny_unknown(&["defaultValue", "removeUnused", "sortKeys", "sourceLanguage"])?;
+
+  Ok(WriteOptions {
+    default_value: fields.string("defaultValue")?,
+    remove_unused: fields.bool("removeUnused")?,
+    sort_keys: fields.bool("sortKeys")?,
+    source_language: fields.string("sourceLanguage")?,
+  })
+}
+
+fn read_output(fields: &Fields) -> Result<OutputOptions, ConfigError> {
+  fields.deny_unknown(&["format", "file"])?;
+
+  let format = fields.string("format")?;
+  if let Some(format) = &format {
+    if !["keys", "detailed", "nested"].contains(&format.as_str()) {
+      return Err(fields.error(
+        "format",
+        format!("must be one of keys, detailed, nested, got '{format}'"),
+      ));
+    }
+  }
+
+  Ok(OutputOptions {
+    format,
+    file: fields.string("file")?,
+  })
+}
+
+fn read_package(fields: &Fields) -> Result<I18nPackage, ConfigError> {
+  fields.deny_unknown(&["packagePath", "members"])?;
+
+  Ok(I18nPackage {
+    package_path: fields.required(fields.string("packagePath"), "packagePath")?,
+    members: fields
+      .required(fields.objects("members"), "members")?
+      .iter()
+      .map(read_member)
+      .collect::<Result<_, _>>()?,
+  })
+}
+
+fn read_member(fields: &Fields) -> Result<Member, ConfigError> {
+  fields.deny_unknown(&[
+    "name",
+    "type",
+    "ns",
+    "keyPrefix",
+    "keyMethods",
+    "keyIndex",
+    "nsIndex",
+    "optionsIndex",
+  ])?;
+
+  Ok(Member {
+    name: fields.required(fields.string("name"), "name")?,
+    r#type: read_i18n_type(fields)?,
+    ns: fields.string("ns")?,
+    key_prefix: fields.string("keyPrefix")?,
+    key_methods: fields
+      .objects("keyMethods")?
+      .map(|methods| methods.iter().map(read_key_method).collect())
+      .transpose()?,
+    key_index: fields.index("keyIndex")?,
+    ns_index: fields.index("nsIndex")?,
+    options_index: fields.index("optionsIndex")?,
+  })
+}
+
+fn read_i18n_type(fields: &Fields) -> Result<I18nType, ConfigError> {
+  match fields.required(fields.string("type"), "type")?.as_str() {
+    "Hook" => Ok(I18nType::Hook),
+    "TMethod" => Ok(I18nType::TMethod),
+    "TransComp" => Ok(I18nType::TransComp),
+    "TranslationComp" => Ok(I18nType::TranslationComp),
+    "HocWrapper" => Ok(I18nType::HocWrapper),
+    "ObjectMemberT" => Ok(I18nType::ObjectMemberT),
+    "FixedT" => Ok(I18nType::FixedT),
+    other => Err(fields.error(
+      "type",
+      format!(
+        "must be one of Hook, TMethod, TransComp, TranslationComp, HocWrapper, ObjectMemberT, FixedT, got '{other}'"
+      ),
+    )),
+  }
+}
+
+fn read_key_method(fields: &Fields) -> Result<KeyMethod, ConfigError> {
+  fields.deny_unknown(&["name", "keyIndex", "nsIndex"])?;
+
+  Ok(KeyMethod {
+    name: fields.required(fields.string("name"), "name")?,
+    key_index: fields.required(fields.index("keyIndex"), "keyIndex")?,
+    ns_index: fields.index("nsIndex")?,
+  })
+}
+
+fn read_template_extractor(fields: &Fields) -> Result<TemplateExtractor, ConfigError> {
+  fields.deny_unknown(&["files", "pipes", "helpers", "patterns"])?;
+
+  Ok(TemplateExtractor {
+    files: fields.required(fields.strings("files"), "files")?,
+    pipes: fields.strings("pipes")?,
+    helpers: fields.strings("helpers")?,
+    patterns: fields.strings("patterns")?,
+  })
+}
+
+/// The fields of a JSON object together with its path in the config, for the error messages
+struct Fields<'a> {
+  object: &'a Map<String, Value>,
+  path: String,
+}
+
+impl<'a> Fields<'a> {
+  fn new(value: &'a Value, path: String) -> Result<Self, ConfigError> {
+    match value {
+      Value::Object(object) => Ok(Self { object, path }),
+      _ => Err(ConfigError {
+        field: path,
+        message: "must be an object".to_string(),
+      }),
+    }
+  }
+
+  fn field_path(&self, name: &str) -> String {
+    if self.path.is_empty() {
+      name.to_string()
+    } else {
+      format!("{}.{name}", self.path)
+    }
+  }
+
+  fn error(&self, name: &str, message: impl Into<String>) -> ConfigError {
+    ConfigError {
+      field: self.field_path(name),
+      message: message.into(),
+    }
+  }
+
+  fn deny_unknown(&self, known: &[&str]) -> Result<(), ConfigError> {
+    match self
+      .object
+      .keys()
+      .find(|key| !known.contains(&key.as_str()))
+    {
+      Some(unknown) => Err(self.error(unknown, "is not a known option")),
+      None => Ok(()),
+    }
+  }
+
+  fn required<T>(
+    &self,
+    value: Result<Option<T>, ConfigError>,
+    name: &str,
+  ) -> Result<T, ConfigError> {
+    value?.ok_or_else(|| self.error(name, "is required"))
+  }
+
+  /// The value of a field, `null` is read as a missing field like `undefined` in JS
+  fn get(&self, name: &str) -> Option<&'a Value> {
+    self.object.get(name).filter(|value| !value.is_null())
+  }
+
+  fn bool(&self, name: &str) -> Result<Option<bool>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(Value::Bool(value)) => Ok(Some(*value)),
+      Some(_) => Err(self.error(name, "must be a boolean")),
+    }
+  }
+
+  fn string(&self, name: &str) -> Result<Option<String>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(Value::String(value)) => Ok(Some(value.clone())),
+      Some(_) => Err(self.error(name, "must be a string")),
+    }
+  }
+
+  fn strings(&self, name: &str) -> Result<Option<Vec<String>>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(Value::Array(values)) => values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| match value {
+          Value::String(value) => Ok(value.clone()),
+          _ => Err(self.error(&format!("{name}[{index}]"), "must be a string")),
+        })
+        .collect::<Result<_, _>>()
+        .map(Some),
+      Some(_) => Err(self.error(name, "must be an array of strings")),
+    }
+  }
+
+  fn index(&self, name: &str) -> Result<Option<u32>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(value) => value
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .map(Some)
+        .ok_or_else(|| self.error(name, "must be a non-negative integer")),
+    }
+  }
+
+  /// `keySeparator` and `nsSeparator` are a string or a boolean
+  fn separator(&self, name: &str) -> Result<Option<Either<String, bool>>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(Value::String(value)) => Ok(Some(Either::A(value.clone()))),
+      Some(Value::Bool(value)) => Ok(Some(Either::B(*value))),
+      Some(_) => Err(self.error(name, "must be a string or a boolean")),
+    }
+  }
+
+  fn object(&self, name: &str) -> Result<Option<Fields<'a>>, ConfigError> {
+    self
+      .get(name)
+      .map(|value| Fields::new(value, self.field_path(name)))
+      .transpose()
+  }
+
+  fn objects(&self, name: &str) -> Result<Option<Vec<Fields<'a>>>, ConfigError> {
+    match self.get(name) {
+      None => Ok(None),
+      Some(Value::Array(values)) => values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| Fields::new(value, self.field_path(&format!("{name}[{index}]"))))
+        .collect::<Result<_, _>>()
+        .map(Some),
+      Some(_) => Err(self.error(name, "must be an array of objects")),
+    }
+  }
+}
+
+/// Blanks the comments and trailing commas of JSONC, the other characters keep their place
+/// so the errors of the JSON parser still point at the right line and column
+fn strip_jsonc(content: &str) -> String {
+  let chars: Vec<char> = content.chars().collect();
+  let mut stripped = String::with_capacity(content.len());
+  let mut index = 0;
+
+  while index < chars.len() {
+    match (chars[index], chars.get(index + 1)) {
+      ('"', _) => {
+        let start = index;
+        index += 1;
+        while index < chars.len() && chars[index] != '"' {
+          index += if chars[index] == '\\' { 2 } else { 1 };
+        }
+        index = (index + 1).min(chars.len());
+        stripped.extend(&chars[start..index]);
+        continue;
+      }
+      ('/', Some('/')) => {
+        while index < chars.len() && chars[index] != '\n' {
+          stripped.push(' ');
+          index += 1;
+        }
+        continue;
+      }
+      ('/', Some('*')) => {
+        let end = (index + 2..chars.len().saturating_sub(1))
+          .find(|end| chars[*end] == '*' && chars[*end + 1] == '/')
+          .map_or(chars.len(), |end| end + 2);
+        for char in &chars[index..end] {
+          stripped.push(if *char == '\n' { '\n' } else { ' ' });
+        }
+        index = end;
+        continue;
+      }
+      (char, _) => stripped.push(char),
+    }
+    index += 1;
+  }
+
+  strip_trailing_commas(&stripped)
+}
+
+/// `[1, 2,]` and `{ "a": 1, }`, once the comments are blanked
+fn strip_trailing_commas(content: &str) -> String {
+  let chars: Vec<char> = content.chars().collect();
+  let mut stripped = String::with_capacity(content.len());
+  let mut in_string = false;
+  let mut index = 0;
+
+  while index < chars.len() {
+    let char = chars[index];
+    if in_string {
+      if char == '\\' {
+        stripped.extend(chars.get(index..index + 2).unwrap_or(&chars[index..]));
+        index += 2;
+        continue;
+      }
+      in_string = char != '"';
+    } else if char == '"' {
+      in_string = true;
+    } else if char == ',' {
+      let next = chars[index + 1..].iter().find(|next| !next.is_whitespace());
+      if matches!(next, Some('}' | ']')) {
+        stripped.push(' ');
+        index += 1;
+        continue;
+      }
+    }
+    stripped.push(char);
+    index += 1;
+  }
+
+  stripped
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{find_config, load_config, read_config, read_payload, strip_jsonc, ConfigError};
+  use crate::node::i18n_types::I18nType;
+  use serde_json::json;
+  use std::env;
+  use std::path::Path;
+
+  #[test]
+  fn reads_payload_fields() {
+    let payload = read_payload(&json!({
+      "tsconfigPath": "tsconfig.json",
+      "entryPaths": ["src/index.tsx"],
+      "extendI18NPackages": [{
+        "packagePath": "@custom/i18n",
+        "members": [{ "name": "useT", "type": "Hook", "ns": "common" }]
+      }],
+      "keySeparator": false,
+      "threads": 2,
+      "failOnParseError": true
+    }))
+    .unwrap();
+
+    assert_eq!(payload.entry_paths, vec!["src/index.tsx"]);
+    assert!(payload.externals.is_empty());
+    let packages = payload.extend_i18n_packages.unwrap();
+    assert!(matches!(packages[0].members[0].r#type, I18nType::Hook));
+    assert_eq!(packages[0].members[0].ns.as_deref(), Some("common"));
+    assert_eq!(payload.threads, Some(2));
+    assert_eq!(payload.fail_on_parse_error, Some(true));
+  }
+
+  #[test]
+  fn points_at_the_invalid_field() {
+    let error = read_payload(&json!({
+      "entryPaths": ["src/index.tsx"],
+      "extendI18NPackages": [{
+        "packagePath": "@custom/i18n",
+        "members": [{ "name": "useT", "type": "Hooks" }]
+      }]
+    }))
+    .err();
+
+    assert_eq!(
+      error.map(|error| error.field),
+      Some("extendI18NPackages[0].members[0].type".to_string())
+    );
+    assert_eq!(
+      read_payload(&json!({ "entryPaths": "src/index.tsx" })).err(),
+      Some(ConfigError {
+        field: "entryPaths".to_string(),
+        message: "must be an array of strings".to_string(),
+      })
+    );
+    assert_eq!(
+      read_payload(&json!({ "entryPath": [] }))
+        .map_err(|error| error.to_string())
+        .err(),
+      Some("`entryPath` is not a known option".to_string())
+    );
+  }
+
+  #[test]
+  fn strips_jsonc_comments_and_trailing_commas() {
+    let content = "{\n  // comment\n  \"a\": \"//not a comment\", /* b */\n  \"b\": [1, 2,],\n}";
+    let stripped = strip_jsonc(content);
+
+    assert_eq!(stripped.lines().count(), content.lines().count());
+    assert_eq!(
+      serde_json::from_str::<serde_json::Value>(&stripped).unwrap(),
+      json!({ "a": "//not a comment", "b": [1, 2] })
+    );
+  }
+
+  #[test]
+  fn loads_the_closest_config_file() {
+    let project = env::current_dir().unwrap().join("tests/fake-project");
+    let config_path = find_config(&project.join("src/I18nCodeCrossFile")).unwrap();
+    assert_eq!(config_path, project.join("i18n-scanner.config.jsonc"));
+
+    let config = load_config(config_path.to_str()).unwrap();
+    assert_eq!(
+      config.payload.entry_paths,
+      vec![project.join("src/TransComp.tsx").display().to_string()]
+    );
+    assert_eq!(
+      config.payload.tsconfig_path,
+      project.join("tsconfig.json").display().to_string()
+    );
+    assert_eq!(
+      config.resources.unwrap().path_pattern,
+      project
+        .join("locales/{{lng}}/{{ns}}.json")
+        .display()
+        .to_string()
+    );
+    assert_eq!(config.write.unwrap().sort_keys, Some(true));
+    assert_eq!(config.output.unwrap().format.as_deref(), Some("detailed"));
+  }
+
+  #[test]
+  fn rejects_invalid_config_sections() {
+    let error = read_config(
+      &json!({ "resources": { "languages": ["en"] } }),
+      Path::new("i18n-scanner.config.json"),
+    )
+    .err();
+
+    assert_eq!(
+      error.map(|error| error.to_string()),
+      Some("`resources.pathPattern` is required".to_string())
+    );
+    assert_eq!(
+      read_config(
+        &json!({ "output": { "format": "yaml" } }),
+        Path::new("i18n-scanner.config.json")
+      )
+      .err()
+      .map(|error| error.field),
+      Some("output.format".to_string())
+    );
+  }
+}
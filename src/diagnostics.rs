@@ -0,0 +1,118 @@
+use crate::collector::key_detail::LineIndex;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// A problem met while reading a file, its keys may be missing from the result
+#[derive(Clone, Debug)]
+#[napi(object)]
+pub struct Diagnostic {
+  pub file_path: String,
+  /// Where the problem is in the file, missing when it is about the whole file
+  pub span: Option<DiagnosticSpan>,
+  pub message: String,
+  pub severity: DiagnosticSeverity,
+}
+
+/// `start` and `end` are byte offsets, `line` and `column` of the start are 1-based
+#[derive(Clone, Debug)]
+#[napi(object)]
+pub struct DiagnosticSpan {
+  pub start: u32,
+  pub end: u32,
+  pub line: u32,
+  pub column: u32,
+}
+
+/// An `Error` means the file was skipped or only partly read, e.g. a syntax error,
+/// a `Warning` is an import which could not be followed
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[napi(string_enum)]
+pub enum DiagnosticSeverity {
+  Error,
+  Warning,
+}
+
+impl DiagnosticSeverity {
+  /// The name of the severity in the JS API
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DiagnosticSeverity::Error => "Error",
+      DiagnosticSeverity::Warning => "Warning",
+    }
+  }
+}
+
+impl Diagnostic {
+  /// A diagnostic about the whole file
+  pub fn file(file_path: &str, message: String, severity: DiagnosticSeverity) -> Self {
+    Self {
+      file_path: file_path.to_string(),
+      span: None,
+      message,
+      severity,
+    }
+  }
+
+  /// A diagnostic about the `start..end` bytes of the source text
+  pub fn at(
+    file_path: &str,
+    source_text: &str,
+    (start, end): (u32, u32),
+    message: String,
+    severity: DiagnosticSeverity,
+  ) -> Self {
+    let (line, column) = LineIndex::new(source_text).line_column(start);
+
+    Self {
+      span: Some(DiagnosticSpan {
+        start,
+        end,
+        line,
+        column,
+      }),
+      ..Self::file(file_path, message, severity)
+    }
+  }
+}
+
+/// `file:line:column message`, the way editors and terminals link to a location
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.span {
+      Some(span) => write!(
+        f,
+        "{}:{}:{} {}",
+        self.file_path, span.line, span.column, self.message
+      ),
+      None => write!(f, "{} {}", self.file_path, self.message),
+    }
+  }
+}
+
+/// The diagnostics of every file of a scan, shared by the analyzer and the collector
+#[derive(Clone, Default)]
+pub struct Diagnostics(Arc<Mutex<Vec<Diagnostic>>>);
+
+impl Diagnostics {
+  pub fn push(&self, diagnostic: Diagnostic) {
+    self.0.lock().unwrap().push(diagnostic);
+  }
+
+  /// Sorted by file and position so they do not depend on which thread read the file first,
+  /// a file walked twice reports its imports once
+  pub fn to_vec(&self) -> Vec<Diagnostic> {
+    let mut diagnostics = self.0.lock().unwrap().clone();
+    diagnostics.sort_by(|a, b| sort_key(a).cmp(&sort_key(b)));
+    diagnostics.dedup_by(|a, b| sort_key(a) == sort_key(b));
+
+    diagnostics
+  }
+}
+
+fn sort_key(diagnostic: &Diagnostic) -> (&str, Option<u32>, &str) {
+  (
+    &diagnostic.file_path,
+    diagnostic.span.as_ref().map(|span| span.start),
+    &diagnostic.message,
+  )
+}
@@ -0,0 +1,189 @@
+use glob::{MatchOptions, Pattern};
+use std::collections::BTreeSet;
+use std::fs;
+
+/// Files of dependencies are never scanned as entries
+const DEFAULT_EXCLUDE: &str = "**/node_modules/**";
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+  case_sensitive: true,
+  require_literal_separator: true,
+  require_literal_leading_dot: false,
+};
+
+/// An entry with one of `*`, `?`, `[` or `{` is a glob pattern rather than a file
+pub fn is_glob(entry: &str) -> bool {
+  entry.contains(['*', '?', '[', '{'])
+}
+
+/// The entries of a scan: the files of `entry_paths`, the source files matching its glob patterns
+/// and the ones matching `include`. The files matching `exclude` or inside `node_modules` are only
+/// left out of the matched ones, a file named by `entry_paths` is always scanned.
+/// Every entry is an absolute path so a file named and matched is scanned once
+pub fn find_entries(
+  entry_paths: &[String],
+  include: &[String],
+  exclude: &[String],
+  is_source_file: impl Fn(&str) -> bool,
+) -> Vec<String> {
+  let exclude: Vec<Pattern> = exclude
+    .iter()
+    .map(String::as_str)
+    .chain([DEFAULT_EXCLUDE])
+    .flat_map(expand_braces)
+    .filter_map(|pattern| match Pattern::new(&pattern) {
+      Ok(pattern) => Some(pattern),
+      Err(err) => {
+        log::warn!("[i18n-scanner-rs] Invalid exclude glob '{pattern}': {err}");
+        None
+      }
+    })
+    .collect();
+
+  let (patterns, files): (Vec<&String>, Vec<&String>) =
+    entry_paths.iter().partition(|entry| is_glob(entry));
+
+  let matched: BTreeSet<String> = patterns
+    .into_iter()
+    .chain(include)
+    .flat_map(|pattern| expand_braces(pattern))
+    .flat_map(|pattern| match glob::glob_with(&pattern, MATCH_OPTIONS) {
+      Ok(paths) => paths.flatten().collect(),
+      Err(err) => {
+        log::warn!("[i18n-scanner-rs] Invalid entry glob '{pattern}': {err}");
+        vec![]
+      }
+    })
+    .filter(|path| path.is_file())
+    .filter_map(|path| {
+      let found = path.to_str()?.to_string();
+      let absolute = canonicalize(&found)?;
+      let is_excluded = exclude.iter().any(|pattern| {
+        pattern.matches_with(&found, MATCH_OPTIONS)
+          || pattern.matches_with(&absolute, MATCH_OPTIONS)
+      });
+
+      (!is_excluded && !absolute.ends_with(".d.ts") && is_source_file(&absolute))
+        .then_some(absolute)
+    })
+    .collect();
+
+  let mut entries: Vec<String> = vec![];
+  // A missing file is kept as it is for the analyzer to report it
+  for path in files
+    .into_iter()
+    .map(|file| canonicalize(file).unwrap_or(file.clone()))
+  {
+    if !entries.contains(&path) {
+      entries.push(path);
+    }
+  }
+  for path in matched {
+    if !entries.contains(&path) {
+      entries.push(path);
+    }
+  }
+
+  entries
+}
+
+/// The analyzer keys the files by their absolute path
+fn canonicalize(path: &str) -> Option<String> {
+  Some(fs::canonicalize(path).ok()?.to_str()?.to_string())
+}
+
+/// `src/*.{ts,tsx}` is `src/*.ts` and `src/*.tsx`, the glob crate does not read braces
+fn expand_braces(pattern: &str) -> Vec<String> {
+  let Some(open) = pattern.find('{') else {
+    return vec![pattern.to_string()];
+  };
+  let Some(close) = pattern[open..].find('}').map(|close| open + close) else {
+    return vec![pattern.to_string()];
+  };
+
+  pattern[open + 1..close]
+    .split(',')
+    .flat_map(|alternative| {
+      expand_braces(&format!(
+        "{}{alternative}{}",
+        &pattern[..open],
+        &pattern[close + 1..]
+      ))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{expand_braces, find_entries};
+  use crate::analyzer::test_utils::test_path;
+
+  fn is_script(path: &str) -> bool {
+    path.ends_with(".ts") || path.ends_with(".tsx")
+  }
+
+  #[test]
+  fn expands_braces() {
+    assert_eq!(
+      expand_braces("src/*.{ts,tsx}"),
+      vec!["src/*.ts", "src/*.tsx"]
+    );
+    assert_eq!(
+      expand_braces("{a,b}/*.{ts,js}"),
+      vec!["a/*.ts", "a/*.js", "b/*.ts", "b/*.js"]
+    );
+    assert_eq!(expand_braces("src/index.ts"), vec!["src/index.ts"]);
+  }
+
+  #[test]
+  fn finds_entries_by_glob() {
+    let fixed_t = test_path("FixedT");
+    let entries = find_entries(&[format!("{fixed_t}/*.ts")], &[], &[], is_script);
+
+    assert_eq!(
+      entries,
+      vec![
+        format!("{fixed_t}/emails.ts"),
+        format!("{fixed_t}/index.ts")
+      ]
+    );
+  }
+
+  #[test]
+  fn includes_every_source_file_but_excluded_ones() {
+    let entry = test_path("TransComp.tsx");
+    let entries = find_entries(
+      std::slice::from_ref(&entry),
+      &[format!("{}/**/*.{{ts,tsx,html}}", test_path("FixedT"))],
+      &["**/emails.ts".to_string()],
+      is_script,
+    );
+
+    assert_eq!(
+      entries,
+      vec![entry, format!("{}/index.ts", test_path("FixedT"))]
+    );
+  }
+
+  #[test]
+  fn finds_an_entry_named_and_matched_once() {
+    let fixed_t = test_path("FixedT");
+    let entries = find_entries(
+      &[
+        format!("{fixed_t}/../FixedT/index.ts"),
+        format!("{fixed_t}/*.ts"),
+      ],
+      &[],
+      &["**/index.ts".to_string()],
+      is_script,
+    );
+
+    assert_eq!(
+      entries,
+      vec![
+        format!("{fixed_t}/index.ts"),
+        format!("{fixed_t}/emails.ts")
+      ]
+    );
+  }
+}
@@ -0,0 +1,79 @@
+use crate::config::ConfigError;
+use crate::diagnostics::Diagnostic;
+use std::fmt;
+
+/// Errors stopping a scan, thrown to JS as an `Error` with a `code` and a `message`
+#[derive(Debug)]
+pub enum ScanError {
+  /// No entry file is given or no file matches the entry globs
+  EmptyEntries,
+  /// A pattern of `externals` is not a valid regular expression
+  InvalidExternal { pattern: String, message: String },
+  /// The config file cannot be read or one of its fields is invalid
+  InvalidConfig(ConfigError),
+  /// The threads parsing and walking the files cannot be started
+  ThreadPool(String),
+  /// Files could not be read or parsed while `failOnParseError` is set
+  ParseErrors(Vec<Diagnostic>),
+  /// A locale file cannot be read or is not valid JSON, it would be overwritten otherwise
+  InvalidResource { file_path: String, message: String },
+  /// A locale file cannot be written
+  WriteResource { file_path: String, message: String },
+}
+
+impl ScanError {
+  /// The `code` of the JS error
+  pub fn code(&self) -> &'static str {
+    match self {
+      ScanError::EmptyEntries => "EMPTY_ENTRIES",
+      ScanError::InvalidExternal { .. } => "INVALID_EXTERNAL",
+      ScanError::InvalidConfig(_) => "INVALID_CONFIG",
+      ScanError::ThreadPool(_) => "THREAD_POOL",
+      ScanError::ParseErrors(_) => "PARSE_ERROR",
+      ScanError::InvalidResource { .. } => "INVALID_RESOURCE",
+      ScanError::WriteResource { .. } => "WRITE_RESOURCE",
+    }
+  }
+}
+
+impl fmt::Display for ScanError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ScanError::EmptyEntries => write!(f, "no entry file is given or matches the entry globs"),
+      ScanError::InvalidExternal { pattern, message } => {
+        write!(f, "invalid external pattern '{pattern}': {message}")
+      }
+      ScanError::InvalidConfig(err) => write!(f, "{err}"),
+      ScanError::ThreadPool(message) => {
+        write!(f, "unable to start the scan threads: {message}")
+      }
+      ScanError::ParseErrors(diagnostics) => {
+        write!(f, "{} file error(s):", diagnostics.len())?;
+        for diagnostic in diagnostics {
+          write!(f, "\n  {diagnostic}")?;
+        }
+        Ok(())
+      }
+      ScanError::InvalidResource { file_path, message } => {
+        write!(f, "unable to load the resource at {file_path}: {message}")
+      }
+      ScanError::WriteResource { file_path, message } => {
+        write!(f, "unable to write the resource at {file_path}: {message}")
+      }
+    }
+  }
+}
+
+impl std::error::Error for ScanError {}
+
+impl From<ConfigError> for ScanError {
+  fn from(err: ConfigError) -> Self {
+    ScanError::InvalidConfig(err)
+  }
+}
+
+impl From<ScanError> for napi::Error<String> {
+  fn from(err: ScanError) -> Self {
+    napi::Error::new(err.code().to_string(), err.to_string())
+  }
+}
@@ -0,0 +1,304 @@
+use crate::plural_rules::{languages_plural_categories, plural_categories};
+use napi::Either;
+use serde_json::{Map, Value};
+
+/// How i18next reads a key, mirrors its `keySeparator` and `nsSeparator` options
+#[derive(Clone, Debug)]
+pub struct KeyConfig {
+  /// Splits a key into nested resource objects, `None` keeps the resources flat
+  pub key_separator: Option<String>,
+  /// Splits the namespace off the key, `None` never reads a namespace from the key
+  pub ns_separator: Option<String>,
+  /// Namespaces a key prefix may name, only a key looking like an object path is split when `None`
+  pub namespaces: Option<Vec<String>>,
+  /// Plural categories of the configured languages, suffixed to keys used with `count`
+  pub plural_categories: Vec<&'static str>,
+  /// Same as react-i18next `transKeepBasicHtmlNodesFor`, elements kept by name in serialized `Trans` children
+  pub trans_keep_basic_html_nodes_for: Vec<String>,
+}
+
+impl Default for KeyConfig {
+  fn default() -> Self {
+    Self {
+      key_separator: Some(".".to_string()),
+      ns_separator: Some(":".to_string()),
+      namespaces: None,
+      plural_categories: plural_categories("en").to_vec(),
+      trans_keep_basic_html_nodes_for: ["br", "strong", "i", "p"].map(String::from).to_vec(),
+    }
+  }
+}
+
+impl KeyConfig {
+  pub fn new(
+    key_separator: Option<Either<String, bool>>,
+    ns_separator: Option<Either<String, bool>>,
+    namespaces: Option<Vec<String>>,
+    languages: Option<Vec<String>>,
+    trans_keep_basic_html_nodes_for: Option<Vec<String>>,
+  ) -> Self {
+    let default = Self::default();
+
+    Self {
+      key_separator: Self::read_separator(key_separator, default.key_separator),
+      ns_separator: Self::read_separator(ns_separator, default.ns_separator),
+      namespaces,
+      plural_categories: languages
+        .map(|languages| languages_plural_categories(&languages))
+        .unwrap_or(default.plural_categories),
+      trans_keep_basic_html_nodes_for: trans_keep_basic_html_nodes_for
+        .unwrap_or(default.trans_keep_basic_html_nodes_for),
+    }
+  }
+
+  /// `false` or an empty string disables the separator, `true` or nothing keeps the default one
+  fn read_separator(
+    separator: Option<Either<String, bool>>,
+    default: Option<String>,
+  ) -> Option<String> {
+    match separator {
+      Some(Either::A(separator)) if !separator.is_empty() => Some(separator),
+      Some(Either::A(_)) | Some(Either::B(false)) => None,
+      Some(Either::B(true)) | None => default,
+    }
+  }
+
+  /// Splits the namespace prefix off a key, `common:save` is the key `save` of `common`,
+  /// the segments after the namespace are joined by the key separator like i18next does
+  pub fn split_namespace<'a>(&self, key: &'a str) -> Option<(&'a str, String)> {
+    let separator = self.ns_separator.as_deref()?;
+    // i18next reads `$t(...)` nestings as they are
+    if !key.contains(separator) || key.contains("$t(") {
+      return None;
+    }
+    // without known namespaces only the prefix of a key looking like an object path is one,
+    // `Note: save first` is a sentence and equal separators leave no way to tell
+    if self.namespaces.is_none()
+      && (self.key_separator.as_deref() == Some(separator) || !self.looks_like_object_path(key))
+    {
+      return None;
+    }
+
+    let mut segments = key.split(separator);
+    let namespace = segments.next()?;
+    let key_separator = self.key_separator.as_deref().unwrap_or(separator);
+    let key = segments.collect::<Vec<_>>().join(key_separator);
+
+    if namespace.is_empty() || key.is_empty() {
+      return None;
+    }
+    if let Some(namespaces) = &self.namespaces {
+      if !namespaces.iter().any(|known| known == namespace) {
+        return None;
+      }
+    }
+
+    Some((namespace, key))
+  }
+
+  /// Same as i18next `looksLikeObjectPath`, a key with none of ` ,?!;` or whose part
+  /// before the first key separator has none of them is not natural language
+  fn looks_like_object_path(&self, key: &str) -> bool {
+    let separators = [self.ns_separator.as_deref(), self.key_separator.as_deref()];
+    let natural_chars: Vec<char> = [' ', ',', '?', '!', ';']
+      .into_iter()
+      .filter(|c| !separators.into_iter().flatten().any(|s| s.contains(*c)))
+      .collect();
+    let is_natural = |text: &str| text.contains(natural_chars.as_slice());
+
+    if !is_natural(key) {
+      return true;
+    }
+    match self.key_separator.as_deref().and_then(|s| key.find(s)) {
+      Some(index) if index > 0 => !is_natural(&key[..index]),
+      _ => false,
+    }
+  }
+
+  /// Suffixes of the keys i18next reads for a call, `t('item', { count })` reads `item_one`
+  /// and `item_other`, `t('friend', { context: 'male' })` reads `friend` and `friend_male`
+  pub fn key_suffixes(&self, has_count: bool, context: Option<&str>) -> Vec<String> {
+    let contexts: Vec<String> = match context {
+      Some(context) if !context.is_empty() => vec![String::new(), format!("_{context}")],
+      _ => vec![String::new()],
+    };
+
+    if !has_count {
+      return contexts;
+    }
+
+    contexts
+      .iter()
+      .flat_map(|context| {
+        self
+          .plural_categories
+          .iter()
+          .map(move |category| format!("{context}_{category}"))
+      })
+      .collect()
+  }
+
+  /// Prepends the `keyPrefix` of a `t`, a namespace prefix stays in front of the key,
+  /// `common:save` under `page` is the key `page.save` of `common`
+  pub fn prefix_key(&self, key_prefix: &str, key: &str) -> String {
+    let key_separator = self.key_separator.as_deref().unwrap_or(".");
+
+    match (self.split_namespace(key), &self.ns_separator) {
+      (Some((namespace, key)), Some(ns_separator)) => {
+        format!("{namespace}{ns_separator}{key_prefix}{key_separator}{key}")
+      }
+      _ => format!("{key_prefix}{key_separator}{key}"),
+    }
+  }
+
+  /// The path of a key in the nested resource objects
+  pub fn key_path<'a>(&self, key: &'a str) -> Vec<&'a str> {
+    match &self.key_separator {
+      Some(separator) => key.split(separator.as_str()).collect(),
+      None => vec![key],
+    }
+  }
+
+  /// Joins a nested resource path back into a key
+  pub fn join_key(&self, prefix: Option<&str>, segment: &str) -> String {
+    match (prefix, &self.key_separator) {
+      (Some(prefix), Some(separator)) => format!("{prefix}{separator}{segment}"),
+      _ => segment.to_string(),
+    }
+  }
+
+  /// Inserts a key at its nested path unless it already exists,
+  /// returns `false` when a translation already takes a part of the path
+  pub fn insert_key(&self, resource: &mut Value, key: &str, value: Value) -> bool {
+    let mut current = resource;
+    let mut segments = self.key_path(key).into_iter().peekable();
+
+    while let Some(segment) = segments.next() {
+      let Value::Object(entries) = current else {
+        return false;
+      };
+
+      if segments.peek().is_none() {
+        entries.entry(segment).or_insert(value);
+        return true;
+      }
+
+      current = entries
+        .entry(segment)
+        .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    true
+  }
+
+  /// Nests the keys of a namespace the way they are stored in its resource file
+  pub fn key_tree(&self, keys: &[String]) -> Value {
+    let mut tree = Value::Object(Map::new());
+
+    for key in keys {
+      if !self.insert_key(&mut tree, key, Value::String(String::new())) {
+        log::warn!("[i18n-scanner-rs] Key '{key}' conflicts with another key of its namespace");
+      }
+    }
+
+    tree
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::KeyConfig;
+  use napi::Either;
+  use serde_json::json;
+
+  #[test]
+  fn read_separators_from_options() {
+    let config = KeyConfig::new(None, Some(Either::B(false)), None, None, None);
+    assert_eq!(config.key_separator.as_deref(), Some("."));
+    assert_eq!(config.ns_separator, None);
+
+    let config = KeyConfig::new(
+      Some(Either::A("_".into())),
+      Some(Either::B(true)),
+      None,
+      None,
+      None,
+    );
+    assert_eq!(config.key_separator.as_deref(), Some("_"));
+    assert_eq!(config.ns_separator.as_deref(), Some(":"));
+  }
+
+  #[test]
+  fn nest_keys_by_key_separator() {
+    let keys = vec![
+      "home.title".to_string(),
+      "home.body".to_string(),
+      "save".to_string(),
+    ];
+
+    assert_eq!(
+      KeyConfig::default().key_tree(&keys),
+      json!({ "home": { "title": "", "body": "" }, "save": "" })
+    );
+    assert_eq!(
+      KeyConfig::new(Some(Either::B(false)), None, None, None, None).key_tree(&keys),
+      json!({ "home.title": "", "home.body": "", "save": "" })
+    );
+  }
+
+  #[test]
+  fn split_namespace_prefix() {
+    let config = KeyConfig::default();
+    assert_eq!(
+      config.split_namespace("common:save"),
+      Some(("common", "save".to_string()))
+    );
+    assert_eq!(
+      config.split_namespace("common:page:title"),
+      Some(("common", "page.title".to_string()))
+    );
+    assert_eq!(
+      config.split_namespace("common:page.Title text"),
+      Some(("common", "page.Title text".to_string()))
+    );
+    assert_eq!(config.split_namespace("save"), None);
+    assert_eq!(config.split_namespace(":save"), None);
+    assert_eq!(config.split_namespace("Note: save first"), None);
+    assert_eq!(config.split_namespace("Are you sure? Yes:No"), None);
+    assert_eq!(config.split_namespace("$t(common:save)"), None);
+
+    let config = KeyConfig::new(None, None, Some(vec!["common".into()]), None, None);
+    assert_eq!(
+      config.split_namespace("common:save first"),
+      Some(("common", "save first".to_string()))
+    );
+    assert_eq!(config.split_namespace("Note: save first"), None);
+  }
+
+  #[test]
+  fn suffix_plural_and_context_keys() {
+    let config = KeyConfig::new(None, None, None, Some(vec!["en".into(), "fr".into()]), None);
+
+    assert_eq!(config.key_suffixes(false, None), vec![""]);
+    assert_eq!(config.key_suffixes(false, Some("male")), vec!["", "_male"]);
+    assert_eq!(
+      config.key_suffixes(true, None),
+      vec!["_one", "_many", "_other"]
+    );
+    assert_eq!(
+      KeyConfig::default().key_suffixes(true, Some("male")),
+      vec!["_one", "_other", "_male_one", "_male_other"]
+    );
+  }
+
+  #[test]
+  fn prefix_keys() {
+    let config = KeyConfig::default();
+
+    assert_eq!(
+      config.prefix_key("page.header", "title"),
+      "page.header.title"
+    );
+    assert_eq!(config.prefix_key("page", "common:save"), "common:page.save");
+  }
+}
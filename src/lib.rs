@@ -1,24 +1,75 @@
 mod analyzer;
+pub mod cli;
 pub mod collector;
+mod config;
+mod diagnostics;
+mod entries;
+mod error;
+mod key_config;
+mod module_cache;
 mod node;
+mod plural_rules;
+mod resources;
+mod sfc;
+mod template_extractor;
 mod walk_utils;
 
 #[macro_use]
 extern crate napi_derive;
 
 use crate::analyzer::i18n_packages::I18nPackage;
+use crate::config::ScanConfig;
+use crate::diagnostics::{Diagnostic, DiagnosticSeverity};
+use crate::error::ScanError;
+use crate::key_config::KeyConfig;
+use crate::module_cache::ModuleCache;
 use crate::node::node_store::NodeStore;
+use crate::resources::locale_resources::{LocaleResources, ResourceOptions};
+use crate::resources::resource_writer::WriteOptions;
+use crate::template_extractor::TemplateExtractor;
 use analyzer::analyzer::Analyzer;
 use collector::collector::Collector;
+use collector::key_detail::KeyDetail;
 use log::info;
+use napi::Either;
 use std::collections::HashMap;
 
 #[napi(object)]
 pub struct Payload {
   pub tsconfig_path: String,
+  /// Entry files, or glob patterns matching them like `src/pages/*.tsx`
   pub entry_paths: Vec<String>,
+  /// Glob patterns of source files scanned as entries on top of `entryPaths`,
+  /// every source file of a directory is scanned whether it is imported or not
+  pub include: Option<Vec<String>>,
+  /// Glob patterns of files left out of the matched entries, matched files in `node_modules` are always left out
+  pub exclude: Option<Vec<String>>,
   pub externals: Vec<String>,
   pub extend_i18n_packages: Option<Vec<I18nPackage>>,
+  /// Number of threads used to parse and walk files, defaults to the number of CPUs
+  pub threads: Option<u32>,
+  /// Same as i18next `keySeparator`, `false` keeps the keys flat, defaults to `.`
+  pub key_separator: Option<Either<String, bool>>,
+  /// Same as i18next `nsSeparator`, `false` never reads a namespace from the key, defaults to `:`
+  pub ns_separator: Option<Either<String, bool>>,
+  /// Namespaces a key prefix before `nsSeparator` may name, only a key looking like an object path is split when omitted
+  pub namespaces: Option<Vec<String>>,
+  /// Languages whose plural forms are collected for keys used with `count`, defaults to `["en"]`
+  pub languages: Option<Vec<String>>,
+  /// Same as react-i18next `transKeepBasicHtmlNodesFor`, defaults to `["br", "strong", "i", "p"]`
+  pub trans_keep_basic_html_nodes_for: Option<Vec<String>>,
+  /// Extractors reading keys out of templates which are not JavaScript, e.g. `.html` or `.hbs`
+  pub template_extractors: Option<Vec<TemplateExtractor>>,
+  /// Throws a `PARSE_ERROR` when a file cannot be read or parsed instead of skipping its keys
+  pub fail_on_parse_error: Option<bool>,
+}
+
+#[napi(object)]
+pub struct ScanDetailedResult {
+  /// { namespace: [{ key, locations }] }
+  pub namespaces: HashMap<String, Vec<KeyDetail>>,
+  /// Files which could not be read or parsed and imports which could not be resolved
+  pub diagnostics: Vec<Diagnostic>,
 }
 
 /// This will follow the below flows to collect all the
@@ -32,40 +83,149 @@ pub struct Payload {
 /// 5. collect the first parameter of i18n function call
 ///    or bypass from another function wrapped by the i18n function
 #[napi]
-pub fn scan(payload: Payload) -> HashMap<String, Vec<String>> {
+pub fn scan(payload: Payload) -> napi::Result<HashMap<String, Vec<String>>, String> {
+  Ok(collect(payload)?.i18n_namespaces)
+}
+
+/// Same as `scan` but every key carries the file path, line, column
+/// and the i18n member type that produced it
+#[napi]
+pub fn scan_detailed(payload: Payload) -> napi::Result<ScanDetailedResult, String> {
+  let collector = collect(payload)?;
+
+  Ok(ScanDetailedResult {
+    namespaces: collector.get_key_details(),
+    diagnostics: collector.get_diagnostics(),
+  })
+}
+
+/// Same as `scan` but the keys of every namespace are nested by `keySeparator`,
+/// the way they are stored in the resource files
+#[napi]
+pub fn scan_nested(payload: Payload) -> napi::Result<HashMap<String, serde_json::Value>, String> {
+  Ok(collect(payload)?.get_key_trees())
+}
+
+/// Scans the project and reports the keys found in the locale resources
+/// that are never referenced, `{ lng: { ns: [key] } }`
+#[napi]
+pub fn find_unused_keys(
+  payload: Payload,
+  resources: ResourceOptions,
+) -> napi::Result<HashMap<String, HashMap<String, Vec<String>>>, String> {
+  let collector = collect(payload)?;
+  let resources = LocaleResources::load(&resources, collector.key_config.clone())?;
+
+  Ok(resources.unused_keys(&collector.i18n_namespaces))
+}
+
+/// Scans the project and reports the collected keys without a translation
+/// in the locale resources, `{ lng: { ns: [{ key, locations }] } }`
+#[napi]
+pub fn find_missing_keys(
+  payload: Payload,
+  resources: ResourceOptions,
+) -> napi::Result<HashMap<String, HashMap<String, Vec<KeyDetail>>>, String> {
+  let collector = collect(payload)?;
+  let resources = LocaleResources::load(&resources, collector.key_config.clone())?;
+
+  Ok(resources.missing_keys(&collector.get_key_details()))
+}
+
+/// Scans the project and merges the collected keys into the locale resources,
+/// missing files are created, returns the written file paths
+#[napi]
+pub fn write_resources(
+  payload: Payload,
+  resources: ResourceOptions,
+  options: Option<WriteOptions>,
+) -> napi::Result<Vec<String>, String> {
+  let collector = collect(payload)?;
+  let mut resources = LocaleResources::load(&resources, collector.key_config.clone())?;
+
+  resources.merge_keys(
+    &collector.i18n_namespaces,
+    &collector.i18n_default_values,
+    &options.unwrap_or_default(),
+  );
+  Ok(resources.write()?)
+}
+
+/// Loads `i18n-scanner.config.json`, the closest one from the working directory
+/// when no path is given, relative paths of the config are resolved from its directory
+#[napi]
+pub fn load_config(config_path: Option<String>) -> napi::Result<ScanConfig, String> {
+  Ok(config::load_config(config_path.as_deref()).map_err(ScanError::from)?)
+}
+
+fn collect(payload: Payload) -> Result<Collector, ScanError> {
   // Initialize logger - use try_init to avoid panic if already initialized
   let _ = env_logger::try_init();
 
-  if payload.entry_paths.len() < 1 {
-    panic!("entry_paths is empty");
-  }
+  let pool = rayon::ThreadPoolBuilder::new()
+    .num_threads(payload.threads.unwrap_or(0) as usize)
+    .build()
+    .map_err(|err| ScanError::ThreadPool(err.to_string()))?;
+
+  pool.install(|| scan_with(payload))
+}
+
+fn scan_with(payload: Payload) -> Result<Collector, ScanError> {
   let node_store = NodeStore::default();
+  let module_cache = ModuleCache::default();
 
   let mut analyzer = Analyzer::new(
     node_store.clone(),
+    module_cache.clone(),
     payload.tsconfig_path,
     payload.externals.clone(),
-  );
+  )?;
 
-  analyzer.seed(
-    &payload.entry_paths.get(0).unwrap(),
-    payload.extend_i18n_packages,
+  let entry_paths = entries::find_entries(
+    &payload.entry_paths,
+    &payload.include.unwrap_or_default(),
+    &payload.exclude.unwrap_or_default(),
+    |file_path| analyzer.is_script_file(file_path),
   );
+  let Some(first_entry) = entry_paths.first() else {
+    return Err(ScanError::EmptyEntries);
+  };
+
+  analyzer.seed(first_entry, payload.extend_i18n_packages);
 
-  payload.entry_paths.iter().for_each(|entry| {
-    analyzer.analyze(entry.clone(), None);
-  });
+  analyzer.analyze(entry_paths);
 
   info!(
     "[i18n-scanner-rs] found {} modules includes i18n",
     node_store.get_all_i18n_nodes().len()
   );
 
-  let mut collector = Collector::new(node_store);
+  let key_config = KeyConfig::new(
+    payload.key_separator,
+    payload.ns_separator,
+    payload.namespaces,
+    payload.languages,
+    payload.trans_keep_basic_html_nodes_for,
+  );
+  let mut collector = Collector::new(node_store, module_cache, key_config);
 
   collector.collect_keys();
+  if let Some(extractors) = &payload.template_extractors {
+    collector.collect_template_keys(extractors)?;
+  }
+
+  if payload.fail_on_parse_error.unwrap_or(false) {
+    let errors: Vec<Diagnostic> = collector
+      .get_diagnostics()
+      .into_iter()
+      .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+      .collect();
+    if !errors.is_empty() {
+      return Err(ScanError::ParseErrors(errors));
+    }
+  }
 
-  collector.i18n_namespaces
+  Ok(collector)
 }
 
 #[allow(dead_code)]
@@ -73,6 +233,8 @@ pub fn scan(payload: Payload) -> HashMap<String, Vec<String>> {
 mod tests {
   use crate::analyzer::analyzer::Analyzer;
   use crate::collector::collector::Collector;
+  use crate::key_config::KeyConfig;
+  use crate::module_cache::ModuleCache;
   use crate::node::node_store::NodeStore;
   use log::info;
   use std::path::PathBuf;
@@ -80,23 +242,26 @@ mod tests {
   #[test]
   fn case_test() {
     let node_store = NodeStore::default();
+    let module_cache = ModuleCache::default();
     let base_path = "";
     let tsconfig_path = PathBuf::from(&base_path).join("tsconfig.json");
     let entry = PathBuf::from(&base_path).join("");
 
     let mut analyzer = Analyzer::new(
       node_store.clone(),
+      module_cache.clone(),
       tsconfig_path.to_str().unwrap().to_string(),
       vec![],
-    );
+    )
+    .unwrap();
 
     analyzer
       .seed(entry.to_str().unwrap(), None)
-      .analyze(entry.to_str().unwrap().to_string(), None);
+      .analyze(vec![entry.to_str().unwrap().to_string()]);
 
     // assert_eq!(node_store.get_i18n_exported_nodes().len(), 1);
 
-    let mut collector = Collector::new(node_store);
+    let mut collector = Collector::new(node_store, module_cache, KeyConfig::default());
     let keys = collector.collect_keys();
 
     info!("{:?}", keys.i18n_namespaces.get("default"));
@@ -0,0 +1,255 @@
+use crate::diagnostics::{Diagnostic, DiagnosticSeverity, Diagnostics};
+use crate::node::node::NodePath;
+use crate::sfc;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Program, SourceType};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::{Semantic, SemanticBuilder};
+use self_cell::self_cell;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex, OnceLock};
+
+struct ModuleSource {
+  allocator: Allocator,
+  source_text: String,
+}
+
+/// The AST of a file and its semantic, which is built once with the AST
+struct ModuleAst<'a> {
+  program: &'a Program<'a>,
+  semantic: Semantic<'a>,
+}
+
+self_cell!(
+  /// Owns both the source text and the arena the AST lives in, neither `Send` nor `Sync`
+  struct ModuleCell {
+    owner: ModuleSource,
+
+    #[covariant]
+    dependent: ModuleAst,
+  }
+);
+
+/// A parsed file shared by the threads of a scan, it only lends the AST and its semantic
+pub struct ParsedModule(ModuleCell);
+
+// SAFETY: the AST and the semantic only point into the arena owned by the same module,
+// so moving it across threads is sound. Sharing it relies on nothing writing to the arena
+// once it is parsed:
+// - the allocator never leaves this file, so nothing allocates into the arena afterwards,
+// - the `Cell` scope, symbol and reference ids of the AST are only written by
+//   `SemanticBuilder`, which is only run by `ParsedModule::parse` before the module is shared
+//   through the `OnceLock` of `ModuleCache` (`builds_semantics_only_while_parsing` checks it),
+// - `program` and `semantic` only lend shared references, which oxc only reads.
+unsafe impl Send for ParsedModule {}
+unsafe impl Sync for ParsedModule {}
+
+impl ParsedModule {
+  /// Parses the source text and builds its semantic, the syntax errors are reported to `diagnostics`
+  pub fn parse(
+    file_path: &str,
+    source_text: String,
+    source_type: SourceType,
+    diagnostics: &Diagnostics,
+  ) -> Self {
+    let source = ModuleSource {
+      allocator: Allocator::default(),
+      source_text,
+    };
+
+    let cell = ModuleCell::new(source, |source| {
+      let parser_return = Parser::new(&source.allocator, &source.source_text, source_type)
+        .with_options(ParseOptions {
+          // Keep scanning on files that have top-level returns in generated outputs.
+          allow_return_outside_function: true,
+          ..ParseOptions::default()
+        })
+        .parse();
+
+      if parser_return.panicked {
+        log::warn!("[i18n-scanner-rs] Parse panicked for file: {file_path}");
+      }
+      if !parser_return.errors.is_empty() {
+        // Surface parse diagnostics together with file path so syntax compatibility issues are easier to locate.
+        log::debug!(
+          "[i18n-scanner-rs] Parse diagnostics for {}: {} error(s)",
+          file_path,
+          parser_return.errors.len()
+        );
+        for error in &parser_return.errors {
+          log::debug!("[i18n-scanner-rs] {error}");
+
+          let message = error.message.to_string();
+          let span = error
+            .labels
+            .iter()
+            .flatten()
+            .next()
+            .map(|label| (label.offset() as u32, (label.offset() + label.len()) as u32));
+          diagnostics.push(match span {
+            Some(span) => Diagnostic::at(
+              file_path,
+              &source.source_text,
+              span,
+              message,
+              DiagnosticSeverity::Error,
+            ),
+            None => Diagnostic::file(file_path, message, DiagnosticSeverity::Error),
+          });
+        }
+      }
+
+      let program = source.allocator.alloc(parser_return.program);
+      let semantic = SemanticBuilder::new().build(program).semantic;
+
+      ModuleAst { program, semantic }
+    });
+
+    ParsedModule(cell)
+  }
+
+  pub fn program(&self) -> &Program<'_> {
+    self.0.borrow_dependent().program
+  }
+
+  /// The semantic of the program, never build another one since it writes the ids of the AST
+  pub fn semantic(&self) -> &Semantic<'_> {
+    &self.0.borrow_dependent().semantic
+  }
+}
+
+type CachedModule = Arc<OnceLock<Option<Arc<ParsedModule>>>>;
+
+/// Every file is read and parsed at most once per scan,
+/// the analyzer, collector and post collector all read the AST from here
+#[derive(Clone, Default)]
+pub struct ModuleCache {
+  modules: Arc<Mutex<HashMap<NodePath, CachedModule>>>,
+  /// Problems met while reading the files of the scan
+  pub diagnostics: Diagnostics,
+}
+
+impl ModuleCache {
+  pub fn get(&self, file_path: &str) -> Option<Arc<ParsedModule>> {
+    let cached = self
+      .modules
+      .lock()
+      .unwrap()
+      .entry(Arc::new(file_path.to_string()))
+      .or_default()
+      .clone();
+
+    // Parse outside of the map lock so other files keep loading in parallel,
+    // concurrent readers of the same file wait for the first parse instead
+    cached
+      .get_or_init(|| self.load(file_path).map(Arc::new))
+      .clone()
+  }
+
+  fn load(&self, file_path: &str) -> Option<ParsedModule> {
+    let source_type = match SourceType::from_path(file_path) {
+      Ok(source_type) => Some(source_type),
+      // Component files get their source type from their script blocks
+      Err(_) if sfc::is_component_file(file_path) => None,
+      Err(_) => return None,
+    };
+    let source_text = match fs::read_to_string(file_path) {
+      Ok(source) => source,
+      Err(err) => {
+        log::warn!("[i18n-scanner-rs] Unable to read file at {file_path}: {err}");
+        self.diagnostics.push(Diagnostic::file(
+          file_path,
+          format!("Unable to read the file: {err}"),
+          DiagnosticSeverity::Error,
+        ));
+        return None;
+      }
+    };
+
+    let (source_text, source_type) = match source_type {
+      Some(source_type) => (source_text, source_type),
+      None => {
+        let script = sfc::extract_script(file_path, &source_text)?;
+        (script.source_text, script.source_type)
+      }
+    };
+
+    Some(ParsedModule::parse(
+      file_path,
+      source_text,
+      source_type,
+      &self.diagnostics,
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::ModuleCache;
+  use crate::analyzer::test_utils::test_path;
+  use std::fs;
+  use std::path::Path;
+  use std::sync::Arc;
+
+  #[test]
+  fn parses_each_file_once() {
+    let cache = ModuleCache::default();
+    let file_path = test_path("TransComp.tsx");
+
+    let first = cache.get(&file_path).expect("parse TransComp.tsx");
+    let second = cache
+      .clone()
+      .get(&file_path)
+      .expect("read cached TransComp.tsx");
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!first.program().body.is_empty());
+    assert!(second.semantic().scoping().symbols_len() > 0);
+  }
+
+  /// Sharing a `ParsedModule` across threads is only sound while no other code writes
+  /// the ids of an AST, which `SemanticBuilder` does through a shared reference
+  #[test]
+  fn builds_semantics_only_while_parsing() {
+    fn visit(dir: &Path, builders: &mut Vec<String>) {
+      for entry in fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+          visit(&path, builders);
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+          && !path.ends_with("module_cache.rs")
+          && fs::read_to_string(&path)
+            .unwrap()
+            .contains("SemanticBuilder")
+        {
+          builders.push(path.display().to_string());
+        }
+      }
+    }
+
+    let mut builders = vec![];
+    visit(
+      &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+      &mut builders,
+    );
+
+    assert!(builders.is_empty(), "SemanticBuilder used in {builders:?}");
+  }
+
+  #[test]
+  fn remembers_unreadable_files() {
+    let cache = ModuleCache::default();
+
+    assert!(cache.get("/not/exists/file.ts").is_none());
+    assert_eq!(
+      cache.diagnostics.to_vec()[0].file_path,
+      "/not/exists/file.ts"
+    );
+    assert!(cache
+      .modules
+      .lock()
+      .unwrap()
+      .contains_key(&"/not/exists/file.ts".to_string()));
+  }
+}
@@ -19,8 +19,10 @@
 ///     const hocWrapper = withTranslation(ns)(Component);
 /// 6. ObjectMemberT
 ///     : i18n.t('key', { ns: 'ns' })
+/// 7. FixedT
+///     : const t = i18n.getFixedT(null, 'ns', 'keyPrefix');
 ///
-/// Those 6 types are calls `t` method to translate except `TransComp`
+/// Those 7 types are calls `t` method to translate except `TransComp`
 
 #[derive(Clone, Debug)]
 #[napi(string_enum)]
@@ -31,10 +33,52 @@ pub enum I18nType {
   TranslationComp,
   HocWrapper,
   ObjectMemberT,
+  FixedT,
+}
+
+impl I18nType {
+  /// The name of the type in the JS API
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      I18nType::Hook => "Hook",
+      I18nType::TMethod => "TMethod",
+      I18nType::TransComp => "TransComp",
+      I18nType::TranslationComp => "TranslationComp",
+      I18nType::HocWrapper => "HocWrapper",
+      I18nType::ObjectMemberT => "ObjectMemberT",
+      I18nType::FixedT => "FixedT",
+    }
+  }
 }
 
 #[derive(Clone, Debug)]
 pub struct I18nMember {
   pub r#type: I18nType,
   pub ns: Option<String>,
+  pub key_prefix: Option<String>,
+  // Methods of an `ObjectMemberT` member reading keys, the preset ones when omitted
+  pub key_methods: Option<Vec<KeyMethod>>,
+  pub arguments: TArguments,
+}
+
+/// Argument indices of a translator call, t(key, options) by default
+#[derive(Clone, Debug, Default)]
+pub struct TArguments {
+  pub key: usize,
+  // The namespace is read from the options when omitted
+  pub ns: Option<usize>,
+  // Right after the key when omitted
+  pub options: Option<usize>,
+}
+
+/// A method of the i18n instance referencing a key, i18n.exists('key') or
+/// i18n.getResource(lng, 'ns', 'key')
+#[derive(Clone, Debug)]
+#[napi(object)]
+pub struct KeyMethod {
+  pub name: String,
+  /// Index of the key argument
+  pub key_index: u32,
+  /// Index of the namespace argument, the namespace is read from the options after the key when omitted
+  pub ns_index: Option<u32>,
 }
@@ -1,11 +1,11 @@
 use crate::node::i18n_types::I18nMember;
 use crate::node::node_store::NodeStore;
 use oxc_ast::ast::SourceType;
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
 
-pub type NodePath = Rc<String>;
+pub type NodePath = Arc<String>;
 
 #[derive(Debug)]
 pub struct Node {
@@ -13,64 +13,71 @@ pub struct Node {
   pub source_type: SourceType,
   node_store: NodeStore,
   // Imports this node's parent
-  imports: RefCell<Vec<NodePath>>,
+  imports: RwLock<Vec<NodePath>>,
   // Import cannot judge what kind of import is
   // only collect the importing members
   // for referencing some cross file variables import
   // { "./xyz": node_path }
-  importing: RefCell<HashMap<String, NodePath>>,
+  importing: RwLock<HashMap<String, NodePath>>,
   // Export can judge what kind of export is but except reexports
   // but analyzer will update the export_members of imports
   // { "useFeTranslate": Trans, "xyz": Trans }
-  exporting_members: RefCell<HashMap<String, Option<I18nMember>>>,
+  exporting_members: RwLock<HashMap<String, Option<I18nMember>>>,
   // flag the node has exported the i18n translator
   // that means we need to tell the import node that we have exported the i18n translator
-  has_exported_i18n_methods: RefCell<bool>,
+  has_exported_i18n_methods: AtomicBool,
   // flag the node has imported the i18n translator
   // that means we need to collect the i18n keys on this file node
-  has_i18n_source_imported: RefCell<bool>,
+  has_i18n_source_imported: AtomicBool,
 }
 
 impl Node {
-  pub fn new(file_path: Rc<String>, node_store: NodeStore) -> Self {
-    let source_type = SourceType::from_path(&file_path.as_str()).unwrap();
+  pub fn new(file_path: Arc<String>, node_store: NodeStore) -> Self {
+    // Component files like `.vue` get their source type from their script blocks
+    let source_type = SourceType::from_path(file_path.as_str()).unwrap_or_default();
 
     Self {
       node_store,
       source_type,
       file_path,
-      imports: RefCell::new(vec![]),
-      importing: RefCell::new(HashMap::new()),
-      exporting_members: RefCell::new(HashMap::new()),
-      has_exported_i18n_methods: RefCell::new(false),
-      has_i18n_source_imported: RefCell::new(false),
+      imports: RwLock::new(vec![]),
+      importing: RwLock::new(HashMap::new()),
+      exporting_members: RwLock::new(HashMap::new()),
+      has_exported_i18n_methods: AtomicBool::new(false),
+      has_i18n_source_imported: AtomicBool::new(false),
     }
   }
 
-  pub fn mark_has_i18n_source_imported(&self) {
-    *self.has_i18n_source_imported.borrow_mut() = true;
+  /// Returns `true` when the flag was not set before
+  pub fn mark_has_i18n_source_imported(&self) -> bool {
+    !self.has_i18n_source_imported.swap(true, Ordering::SeqCst)
   }
 
   pub fn has_i18n_source_imported(&self) -> bool {
-    *self.has_i18n_source_imported.borrow()
+    self.has_i18n_source_imported.load(Ordering::SeqCst)
   }
 
   pub fn has_exported_i18n_methods(&self) -> bool {
-    *self.has_exported_i18n_methods.borrow()
+    self.has_exported_i18n_methods.load(Ordering::SeqCst)
   }
 
-  pub fn insert_imports(&self, parent_path: Rc<String>) {
-    self.imports.borrow_mut().push(parent_path);
+  /// Each importer is kept once, a node walked again links its imports again
+  pub fn insert_imports(&self, parent_path: Arc<String>) {
+    let mut imports = self.imports.write().unwrap();
+    if !imports.contains(&parent_path) {
+      imports.push(parent_path);
+    }
   }
 
   pub fn insert_exporting(&self, member: String, i18n_member: Option<I18nMember>) {
     if i18n_member.is_some() {
-      *self.has_exported_i18n_methods.borrow_mut() = true;
+      self.has_exported_i18n_methods.store(true, Ordering::SeqCst);
     }
 
     self
       .exporting_members
-      .borrow_mut()
+      .write()
+      .unwrap()
       .insert(member, i18n_member);
   }
 
@@ -82,30 +89,32 @@ impl Node {
 
     node.insert_imports(self.file_path.clone());
 
-    let mut importing = self.importing.borrow_mut();
-
-    importing.insert(specifier, path);
+    self.importing.write().unwrap().insert(specifier, path);
 
-    drop(importing);
     Ok(())
   }
 
-  pub fn insert_importing(&self, specifier: String, source_path: Rc<String>) {
-    let mut importing = self.importing.borrow_mut();
-
-    importing.insert(specifier, source_path);
+  pub fn insert_importing(&self, specifier: String, source_path: Arc<String>) {
+    self
+      .importing
+      .write()
+      .unwrap()
+      .insert(specifier, source_path);
+  }
 
-    drop(importing);
+  pub fn get_importing(&self) -> HashMap<String, NodePath> {
+    self.importing.read().unwrap().clone()
   }
 
   pub fn get_exporting_members(&self) -> HashMap<String, Option<I18nMember>> {
-    self.exporting_members.borrow().clone()
+    self.exporting_members.read().unwrap().clone()
   }
 
   pub fn get_exporting_i18n_members(&self) -> HashMap<String, I18nMember> {
     self
       .exporting_members
-      .borrow()
+      .read()
+      .unwrap()
       .iter()
       .filter_map(|(name, translation_method)| {
         if translation_method.is_some() {
@@ -116,10 +125,26 @@ impl Node {
       .collect()
   }
 
-  pub fn get_importing_node(&self, specifier: &str) -> Option<Rc<Node>> {
-    if let Some(node_path) = self.importing.borrow().get(specifier) {
-      return self.node_store.get_by_node_path(node_path);
-    }
-    None
+  pub fn get_importing_node(&self, specifier: &str) -> Option<Arc<Node>> {
+    let node_path = self.importing.read().unwrap().get(specifier).cloned();
+    node_path.and_then(|node_path| self.node_store.get_by_node_path(&node_path))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Node;
+  use crate::node::node_store::NodeStore;
+  use std::sync::Arc;
+
+  #[test]
+  fn keeps_each_importer_once() {
+    let node = Node::new(Arc::new("/src/hook.ts".into()), NodeStore::default());
+    let importer = Arc::new("/src/index.ts".to_string());
+
+    node.insert_imports(importer.clone());
+    node.insert_imports(importer.clone());
+
+    assert_eq!(*node.imports.read().unwrap(), vec![importer]);
   }
 }
@@ -1,65 +1,68 @@
 use crate::node::node::{Node, NodePath};
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::{Arc, RwLock};
 
-pub type NodeRecord = HashMap<NodePath, Rc<Node>>;
+pub type NodeRecord = HashMap<NodePath, Arc<Node>>;
 
 #[derive(Default, Debug)]
-pub struct NodeStore(Rc<RefCell<NodeRecord>>);
+pub struct NodeStore(Arc<RwLock<NodeRecord>>);
 
 impl NodeStore {
   pub fn new(node_record: NodeRecord) -> Self {
-    Self(Rc::new(RefCell::new(node_record)))
+    Self(Arc::new(RwLock::new(node_record)))
   }
-  pub fn get_by_node_path(&self, node_path: &NodePath) -> Option<Rc<Node>> {
-    self.0.borrow().get(node_path).map(|node| Rc::clone(node))
+  pub fn get_by_node_path(&self, node_path: &NodePath) -> Option<Arc<Node>> {
+    self.0.read().unwrap().get(node_path).map(Arc::clone)
   }
-  pub fn get_node(&self, file_path: &str) -> Option<Rc<Node>> {
+  pub fn get_node(&self, file_path: &str) -> Option<Arc<Node>> {
     self
       .0
-      .borrow()
+      .read()
+      .unwrap()
       .get(&file_path.to_string())
-      .map(|node| Rc::clone(node))
+      .map(Arc::clone)
   }
 
-  pub fn get_path_and_node(&self, file_path: &str) -> Option<(NodePath, Rc<Node>)> {
+  pub fn get_path_and_node(&self, file_path: &str) -> Option<(NodePath, Arc<Node>)> {
     self
       .0
-      .borrow()
+      .read()
+      .unwrap()
       .get_key_value(&file_path.to_string())
-      .map(|(k, v)| (k.clone(), Rc::clone(v)))
+      .map(|(k, v)| (k.clone(), Arc::clone(v)))
   }
 
-  pub fn insert_node(&self, file_path: Rc<String>, node: Rc<Node>) {
-    self.0.borrow_mut().insert(file_path, node);
+  pub fn insert_node(&self, file_path: Arc<String>, node: Arc<Node>) {
+    self.0.write().unwrap().insert(file_path, node);
   }
   pub fn clone(&self) -> Self {
-    Self(Rc::clone(&self.0))
+    Self(Arc::clone(&self.0))
   }
 
   pub fn get_all_nodes(&self) -> NodeRecord {
-    self.0.borrow().clone()
+    self.0.read().unwrap().clone()
   }
 
   pub fn get_i18n_exported_nodes(&self) -> NodeRecord {
     self
       .0
-      .borrow()
+      .read()
+      .unwrap()
       .iter()
       .filter(|(_, node)| node.has_exported_i18n_methods())
-      .map(|(k, v)| (k.clone(), Rc::clone(v)))
+      .map(|(k, v)| (k.clone(), Arc::clone(v)))
       .collect()
   }
 
   pub fn get_all_i18n_nodes(&self) -> NodeRecord {
     self
       .0
-      .borrow()
+      .read()
+      .unwrap()
       .iter()
       .filter_map(|(k, node)| {
         if node.has_i18n_source_imported() {
-          Some((k.clone(), Rc::clone(node)))
+          Some((k.clone(), Arc::clone(node)))
         } else {
           None
         }
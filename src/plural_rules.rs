@@ -0,0 +1,78 @@
+/// CLDR cardinal plural categories, in the order i18next suffixes keys with them
+const CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// CLDR cardinal plural categories of a language, `en-US` reads the ones of `en`,
+/// unknown languages fall back to `one` and `other`
+pub fn plural_categories(lng: &str) -> &'static [&'static str] {
+  let language = lng
+    .split(['-', '_'])
+    .next()
+    .unwrap_or(lng)
+    .to_ascii_lowercase();
+
+  match language.as_str() {
+    "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" | "lo" | "my" | "km" | "yue" | "jv" | "su" => {
+      &["other"]
+    }
+    "fr" | "es" | "it" | "pt" | "ca" => &["one", "many", "other"],
+    "ru" | "uk" | "pl" | "be" | "lt" | "cs" | "sk" => &["one", "few", "many", "other"],
+    "ro" | "hr" | "sr" | "bs" | "mo" => &["one", "few", "other"],
+    "lv" => &["zero", "one", "other"],
+    "he" | "iw" => &["one", "two", "other"],
+    "sl" => &["one", "two", "few", "other"],
+    "ga" | "gd" | "mt" => &["one", "two", "few", "many", "other"],
+    "ar" | "cy" => &["zero", "one", "two", "few", "many", "other"],
+    _ => &["one", "other"],
+  }
+}
+
+/// Every category used by one of the languages, ordered like CLDR lists them
+pub fn languages_plural_categories(languages: &[String]) -> Vec<&'static str> {
+  CATEGORIES
+    .into_iter()
+    .filter(|category| {
+      languages
+        .iter()
+        .any(|lng| plural_categories(lng).contains(category))
+    })
+    .collect()
+}
+
+/// Whether a language reads a collected key, a plural form like `item_many` collected along
+/// with `item_other` is only read by the languages having its category
+pub fn language_reads_key(lng: &str, key: &str, is_collected: impl Fn(&str) -> bool) -> bool {
+  match key.rsplit_once('_') {
+    Some((base, category))
+      if CATEGORIES.contains(&category) && is_collected(&format!("{base}_other")) =>
+    {
+      plural_categories(lng).contains(&category)
+    }
+    _ => true,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{language_reads_key, languages_plural_categories, plural_categories};
+
+  #[test]
+  fn read_categories_by_language() {
+    assert_eq!(plural_categories("en-US"), &["one", "other"]);
+    assert_eq!(plural_categories("zh_CN"), &["other"]);
+    assert_eq!(
+      languages_plural_categories(&["en".into(), "ru".into()]),
+      vec!["one", "few", "many", "other"]
+    );
+  }
+
+  #[test]
+  fn read_plural_forms_by_language() {
+    let collected = |key: &str| ["item_one", "item_many", "item_other"].contains(&key);
+
+    assert!(!language_reads_key("en", "item_many", collected));
+    assert!(language_reads_key("fr", "item_many", collected));
+    assert!(language_reads_key("en", "item_one", collected));
+    // Not part of a plural
+    assert!(language_reads_key("en", "page_many", collected));
+  }
+}
@@ -0,0 +1,380 @@
+use crate::collector::key_detail::KeyDetail;
+use crate::error::ScanError;
+use crate::key_config::KeyConfig;
+use crate::plural_rules::language_reads_key;
+use glob::Pattern;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+
+const LNG_PLACEHOLDER: &str = "{{lng}}";
+const NS_PLACEHOLDER: &str = "{{ns}}";
+
+#[napi(object)]
+pub struct ResourceOptions {
+  /// Where the locale files live, e.g. `locales/{{lng}}/{{ns}}.json`
+  pub path_pattern: String,
+  /// Languages to load, every language matched by `path_pattern` when omitted
+  pub languages: Option<Vec<String>>,
+  /// Resource namespace holding the keys collected without a namespace, defaults to `translation`
+  pub default_namespace: Option<String>,
+}
+
+/// Locale JSON files loaded from a `{{lng}}/{{ns}}` path pattern
+pub struct LocaleResources {
+  path_pattern: String,
+  default_namespace: String,
+  pub key_config: KeyConfig,
+  /// The configured languages, or every language found when none is configured
+  pub languages: Vec<String>,
+  // { lng: { ns: resource } }
+  pub resources: HashMap<String, HashMap<String, Value>>,
+}
+
+impl LocaleResources {
+  /// Loads every resource file, a file which cannot be read or parsed fails the load
+  /// so it is never overwritten with only the collected keys
+  pub fn load(options: &ResourceOptions, key_config: KeyConfig) -> Result<Self, ScanError> {
+    let mut resources: HashMap<String, HashMap<String, Value>> = HashMap::new();
+
+    for (lng, ns, file_path) in Self::find_files(&options.path_pattern) {
+      if let Some(languages) = &options.languages {
+        if !languages.contains(&lng) {
+          continue;
+        }
+      }
+
+      let resource = fs::read_to_string(&file_path)
+        .map_err(|err| err.to_string())
+        .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|err| err.to_string()))
+        .map_err(|message| ScanError::InvalidResource {
+          file_path: file_path.clone(),
+          message,
+        })?;
+
+      resources.entry(lng).or_default().insert(ns, resource);
+    }
+
+    let mut languages = options
+      .languages
+      .clone()
+      .unwrap_or_else(|| resources.keys().cloned().collect());
+    languages.sort();
+
+    Ok(Self {
+      path_pattern: options.path_pattern.clone(),
+      key_config,
+      languages,
+      default_namespace: options
+        .default_namespace
+        .clone()
+        .unwrap_or_else(|| "translation".to_string()),
+      resources,
+    })
+  }
+
+  /// Lists the `(lng, ns, file_path)` of every file matching the path pattern
+  fn find_files(path_pattern: &str) -> Vec<(String, String, String)> {
+    let glob_pattern = path_pattern
+      .split(LNG_PLACEHOLDER)
+      .map(|part| {
+        part
+          .split(NS_PLACEHOLDER)
+          .map(Pattern::escape)
+          .collect::<Vec<_>>()
+          .join("*")
+      })
+      .collect::<Vec<_>>()
+      .join("*");
+
+    let capture_pattern = path_pattern
+      .split(LNG_PLACEHOLDER)
+      .map(|part| {
+        part
+          .split(NS_PLACEHOLDER)
+          .map(regex::escape)
+          .collect::<Vec<_>>()
+          .join("(?P<ns>[^/\\\\]+)")
+      })
+      .collect::<Vec<_>>()
+      .join("(?P<lng>[^/\\\\]+)");
+    let Ok(capture_regex) = Regex::new(&format!("^{capture_pattern}$")) else {
+      log::warn!("[i18n-scanner-rs] Invalid resource path pattern: {path_pattern}");
+      return vec![];
+    };
+
+    let Ok(paths) = glob::glob(&glob_pattern) else {
+      log::warn!("[i18n-scanner-rs] Invalid resource path pattern: {path_pattern}");
+      return vec![];
+    };
+
+    let mut files: Vec<(String, String, String)> = paths
+      .flatten()
+      .filter_map(|path| {
+        let file_path = path.to_str()?.to_string();
+        let captures = capture_regex.captures(&file_path)?;
+        let lng = captures.name("lng")?.as_str().to_string();
+        let ns = captures.name("ns")?.as_str().to_string();
+
+        Some((lng, ns, file_path))
+      })
+      .collect();
+    files.sort();
+
+    files
+  }
+
+  /// The file a resource is read from and written to
+  pub fn resource_path(&self, lng: &str, ns: &str) -> String {
+    self
+      .path_pattern
+      .replace(LNG_PLACEHOLDER, lng)
+      .replace(NS_PLACEHOLDER, ns)
+  }
+
+  /// Maps a collected namespace to the namespace of its resource file
+  pub fn resource_namespace<'a>(&'a self, namespace: &'a str) -> &'a str {
+    if namespace == "default" {
+      &self.default_namespace
+    } else {
+      namespace
+    }
+  }
+
+  /// Flattens a resource into its keys, nested objects are joined with the key separator
+  pub fn resource_keys(&self, resource: &Value) -> BTreeSet<String> {
+    fn flatten(
+      key_config: &KeyConfig,
+      value: &Value,
+      prefix: Option<&str>,
+      keys: &mut BTreeSet<String>,
+    ) {
+      match (value, prefix) {
+        (Value::Object(entries), None) => {
+          for (key, value) in entries {
+            flatten(key_config, value, Some(key), keys);
+          }
+        }
+        (Value::Object(entries), Some(prefix)) if key_config.key_separator.is_some() => {
+          for (key, value) in entries {
+            let key = key_config.join_key(Some(prefix), key);
+            flatten(key_config, value, Some(&key), keys);
+          }
+        }
+        (_, Some(key)) => {
+          keys.insert(key.to_string());
+        }
+        (_, None) => {}
+      }
+    }
+
+    let mut keys = BTreeSet::new();
+    flatten(&self.key_config, resource, None, &mut keys);
+    keys
+  }
+
+  /// Groups the collected keys by the namespace of their resource file
+  pub fn used_keys<'a>(
+    &'a self,
+    namespaces: &'a HashMap<String, Vec<String>>,
+  ) -> HashMap<&'a str, BTreeSet<&'a str>> {
+    let mut used_keys: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for (namespace, keys) in namespaces {
+      used_keys
+        .entry(self.resource_namespace(namespace))
+        .or_default()
+        .extend(keys.iter().map(|key| key.as_str()));
+    }
+
+    used_keys
+  }
+
+  /// The collected keys of a namespace a language reads, without the plural forms
+  /// of the other languages
+  fn language_keys<'a>(lng: &str, keys: &BTreeSet<&'a str>) -> BTreeSet<&'a str> {
+    keys
+      .iter()
+      .filter(|key| language_reads_key(lng, key, |other| keys.contains(other)))
+      .copied()
+      .collect()
+  }
+
+  /// Keys present in the resources but never collected, `{ lng: { ns: [key] } }`
+  pub fn unused_keys(
+    &self,
+    namespaces: &HashMap<String, Vec<String>>,
+  ) -> HashMap<String, HashMap<String, Vec<String>>> {
+    let used_keys = self.used_keys(namespaces);
+
+    self
+      .resources
+      .iter()
+      .map(|(lng, resources)| {
+        let unused = resources
+          .iter()
+          .filter_map(|(ns, resource)| {
+            let used = used_keys
+              .get(ns.as_str())
+              .map(|used| Self::language_keys(lng, used));
+            let keys: Vec<String> = self
+              .resource_keys(resource)
+              .into_iter()
+              .filter(|key| {
+                !used
+                  .as_ref()
+                  .is_some_and(|used| used.contains(key.as_str()))
+              })
+              .collect();
+
+            (!keys.is_empty()).then(|| (ns.clone(), keys))
+          })
+          .collect();
+
+        (lng.clone(), unused)
+      })
+      .collect()
+  }
+
+  /// Collected keys without a translation, `{ lng: { ns: [{ key, locations }] } }`,
+  /// a language without any resource file misses every key
+  pub fn missing_keys(
+    &self,
+    key_details: &HashMap<String, Vec<KeyDetail>>,
+  ) -> HashMap<String, HashMap<String, Vec<KeyDetail>>> {
+    self
+      .languages
+      .iter()
+      .map(|lng| {
+        let resources = self.resources.get(lng);
+        let mut missing: HashMap<String, Vec<KeyDetail>> = HashMap::new();
+
+        for (namespace, details) in key_details {
+          let ns = self.resource_namespace(namespace);
+          let translated = resources
+            .and_then(|resources| resources.get(ns))
+            .map(|resource| self.resource_keys(resource))
+            .unwrap_or_default();
+
+          let collected = details.iter().map(|detail| detail.key.as_str()).collect();
+          let read = Self::language_keys(lng, &collected);
+          let details = details
+            .iter()
+            .filter(|detail| {
+              read.contains(detail.key.as_str()) && !translated.contains(&detail.key)
+            })
+            .cloned();
+          missing.entry(ns.to_string()).or_default().extend(details);
+        }
+        missing.retain(|_, details| !details.is_empty());
+
+        (lng.clone(), missing)
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{LocaleResources, ResourceOptions};
+  use crate::collector::key_detail::KeyDetail;
+  use crate::collector::test_utils::collect;
+  use crate::key_config::KeyConfig;
+  use std::collections::HashMap;
+
+  fn resource_options(languages: Option<Vec<String>>) -> ResourceOptions {
+    ResourceOptions {
+      path_pattern: "tests/fake-project/locales/{{lng}}/{{ns}}.json".into(),
+      languages,
+      default_namespace: None,
+    }
+  }
+
+  #[test]
+  fn load_resources_from_path_pattern() {
+    let resources = LocaleResources::load(&resource_options(None), KeyConfig::default()).unwrap();
+
+    let mut languages: Vec<&String> = resources.resources.keys().collect();
+    languages.sort();
+    assert_eq!(languages, vec!["en", "zh"]);
+    assert_eq!(resources.resources["en"].len(), 2);
+
+    let only_zh = LocaleResources::load(
+      &resource_options(Some(vec!["zh".into()])),
+      KeyConfig::default(),
+    )
+    .unwrap();
+    assert_eq!(only_zh.resources.len(), 1);
+  }
+
+  #[test]
+  fn report_unused_keys() {
+    let (_, collector) = collect("HookWithNamespace.tsx".into(), None);
+    let resources = LocaleResources::load(&resource_options(None), KeyConfig::default()).unwrap();
+
+    let unused = resources.unused_keys(&collector.i18n_namespaces);
+
+    assert_eq!(
+      unused["en"]["namespace_1"],
+      vec!["UNUSED_KEY".to_string(), "legacy.title".to_string()]
+    );
+    assert_eq!(
+      unused["en"]["translation"],
+      vec!["UNUSED_DEFAULT_KEY".to_string()]
+    );
+    assert!(unused["zh"].is_empty());
+  }
+
+  #[test]
+  fn report_missing_keys_with_locations() {
+    let (_, collector) = collect("HookWithNamespace.tsx".into(), None);
+    let mut options = resource_options(None);
+    options.languages = Some(vec!["en".into(), "fr".into()]);
+    let resources = LocaleResources::load(&options, KeyConfig::default()).unwrap();
+
+    let missing = resources.missing_keys(&collector.get_key_details());
+
+    assert!(missing["en"].is_empty());
+
+    let fr_missing = &missing["fr"]["namespace_1"];
+    assert_eq!(fr_missing.len(), 1);
+    assert_eq!(fr_missing[0].key, "HOOK_WITH_NAMESPACE");
+    assert_eq!(
+      (
+        fr_missing[0].locations[0].line,
+        fr_missing[0].locations[0].column
+      ),
+      (7, 12)
+    );
+  }
+
+  #[test]
+  fn report_plural_forms_by_language() {
+    let keys = ["ITEM_one", "ITEM_many", "ITEM_other"];
+    let details = HashMap::from([(
+      "translation".to_string(),
+      keys
+        .iter()
+        .map(|key| KeyDetail {
+          key: key.to_string(),
+          locations: vec![],
+          default_value: None,
+        })
+        .collect(),
+    )]);
+    let mut options = resource_options(None);
+    options.languages = Some(vec!["en".into(), "fr".into()]);
+    let resources = LocaleResources::load(&options, KeyConfig::default()).unwrap();
+
+    let missing = resources.missing_keys(&details);
+    let missing_keys = |lng: &str| -> Vec<String> {
+      missing[lng]["translation"]
+        .iter()
+        .map(|detail| detail.key.clone())
+        .collect()
+    };
+
+    assert_eq!(missing_keys("en"), vec!["ITEM_one", "ITEM_other"]);
+    assert_eq!(missing_keys("fr"), keys);
+  }
+}
@@ -0,0 +1,2 @@
+pub mod locale_resources;
+pub mod resource_writer;
@@ -0,0 +1,329 @@
+use crate::error::ScanError;
+use crate::key_config::KeyConfig;
+use crate::plural_rules::language_reads_key;
+use crate::resources::locale_resources::LocaleResources;
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct WriteOptions {
+  /// Value given to the keys without a translation yet, defaults to an empty string
+  pub default_value: Option<String>,
+  /// Removes the translations of keys that are no longer collected
+  pub remove_unused: Option<bool>,
+  /// Sorts the keys alphabetically, the existing order is kept otherwise
+  pub sort_keys: Option<bool>,
+  /// Language whose new keys are filled with the default value read from the code when there is one
+  pub source_language: Option<String>,
+}
+
+impl LocaleResources {
+  /// Merges the collected keys into the resources of every language,
+  /// existing translations are kept as is
+  pub fn merge_keys(
+    &mut self,
+    namespaces: &HashMap<String, Vec<String>>,
+    default_values: &HashMap<String, HashMap<String, String>>,
+    options: &WriteOptions,
+  ) {
+    let default_value = options.default_value.clone().unwrap_or_default();
+    let key_config = self.key_config.clone();
+    let mut code_default_values: HashMap<String, HashMap<String, String>> = HashMap::new();
+    for (namespace, values) in default_values {
+      code_default_values
+        .entry(self.resource_namespace(namespace).to_string())
+        .or_default()
+        .extend(values.clone());
+    }
+    let used_keys: HashMap<String, BTreeSet<String>> = self
+      .used_keys(namespaces)
+      .into_iter()
+      .map(|(ns, keys)| {
+        (
+          ns.to_string(),
+          keys.into_iter().map(|key| key.to_string()).collect(),
+        )
+      })
+      .collect();
+
+    for lng in self.languages.clone() {
+      let is_source_language = options.source_language.as_ref() == Some(&lng);
+      // The plural forms of the other languages are neither added nor kept
+      let used_keys: HashMap<&String, BTreeSet<String>> = used_keys
+        .iter()
+        .map(|(ns, keys)| {
+          let keys = keys
+            .iter()
+            .filter(|key| language_reads_key(&lng, key, |other| keys.contains(other)))
+            .cloned()
+            .collect();
+          (ns, keys)
+        })
+        .collect();
+      let resources = self.resources.entry(lng).or_default();
+
+      for (ns, keys) in &used_keys {
+        let resource = resources
+          .entry(ns.to_string())
+          .or_insert_with(|| Value::Object(Map::new()));
+
+        for key in keys {
+          let code_default_value = code_default_values
+            .get(*ns)
+            .and_then(|values| values.get(key))
+            .filter(|_| is_source_language);
+          let value = Value::String(code_default_value.unwrap_or(&default_value).clone());
+          if !key_config.insert_key(resource, key, value) {
+            log::warn!("[i18n-scanner-rs] Key '{key}' conflicts with an existing translation");
+          }
+        }
+      }
+
+      for (ns, resource) in resources.iter_mut() {
+        if options.remove_unused.unwrap_or(false) {
+          let empty = BTreeSet::new();
+          remove_unused(
+            resource,
+            None,
+            used_keys.get(ns).unwrap_or(&empty),
+            &key_config,
+          );
+        }
+        if options.sort_keys.unwrap_or(false) {
+          sort_keys(resource);
+        }
+      }
+    }
+  }
+
+  /// Writes the resources of every language back to the path pattern,
+  /// returns the written file paths or fails on the first file which cannot be written
+  pub fn write(&self) -> Result<Vec<String>, ScanError> {
+    let mut written = vec![];
+
+    for lng in &self.languages {
+      let Some(resources) = self.resources.get(lng) else {
+        continue;
+      };
+
+      for (ns, resource) in resources {
+        let file_path = self.resource_path(lng, ns);
+        let write_error = |message: String| ScanError::WriteResource {
+          file_path: file_path.clone(),
+          message,
+        };
+        let content = serde_json::to_string_pretty(resource)
+          .map_err(|err| write_error(err.to_string()))?
+          + "\n";
+
+        if let Some(parent) = Path::new(&file_path).parent() {
+          fs::create_dir_all(parent).map_err(|err| write_error(err.to_string()))?;
+        }
+        fs::write(&file_path, content).map_err(|err| write_error(err.to_string()))?;
+
+        written.push(file_path);
+      }
+    }
+
+    written.sort();
+    Ok(written)
+  }
+}
+
+/// Drops the translations whose key is not collected, and the objects left empty
+fn remove_unused(
+  resource: &mut Value,
+  prefix: Option<&str>,
+  used_keys: &BTreeSet<String>,
+  key_config: &KeyConfig,
+) {
+  let Value::Object(entries) = resource else {
+    return;
+  };
+
+  entries.retain(|segment, value| {
+    let key = key_config.join_key(prefix, segment);
+
+    match value {
+      Value::Object(_) if prefix.is_none() || key_config.key_separator.is_some() => {
+        if used_keys.contains(&key) {
+          return true;
+        }
+        remove_unused(value, Some(&key), used_keys, key_config);
+        value.as_object().is_some_and(|entries| !entries.is_empty())
+      }
+      _ => used_keys.contains(&key),
+    }
+  });
+}
+
+fn sort_keys(resource: &mut Value) {
+  if let Value::Object(entries) = resource {
+    entries.sort_keys();
+    entries.values_mut().for_each(sort_keys);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::WriteOptions;
+  use crate::collector::test_utils::collect;
+  use crate::key_config::KeyConfig;
+  use crate::resources::locale_resources::{LocaleResources, ResourceOptions};
+  use serde_json::json;
+  use std::env;
+  use std::fs;
+  use std::path::Path;
+
+  fn copy_locales(name: &str) -> String {
+    let target = env::temp_dir().join(format!("i18n-scanner-rs-{name}"));
+    let _ = fs::remove_dir_all(&target);
+
+    for lng in ["en", "zh"] {
+      let source = Path::new("tests/fake-project/locales").join(lng);
+      fs::create_dir_all(target.join(lng)).unwrap();
+      for entry in fs::read_dir(source).unwrap() {
+        let entry = entry.unwrap();
+        fs::copy(entry.path(), target.join(lng).join(entry.file_name())).unwrap();
+      }
+    }
+
+    target.to_str().unwrap().to_string()
+  }
+
+  fn write_resources(name: &str, entry: &str, options: WriteOptions) -> String {
+    let (_, collector) = collect(entry.into(), None);
+    let locales = copy_locales(name);
+    let mut resources =
+      LocaleResources::load(&locale_options(&locales), KeyConfig::default()).unwrap();
+
+    resources.merge_keys(
+      &collector.i18n_namespaces,
+      &collector.i18n_default_values,
+      &options,
+    );
+    let written = resources.write().unwrap();
+    assert!(written
+      .iter()
+      .any(|file_path| file_path.starts_with(&format!("{locales}/fr/"))));
+
+    locales
+  }
+
+  fn read_resource(file_path: String) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(file_path).unwrap()).unwrap()
+  }
+
+  #[test]
+  fn merge_keys_into_resources() {
+    let locales = write_resources(
+      "merge",
+      "HookWithNamespace.tsx",
+      WriteOptions {
+        default_value: Some("TODO".into()),
+        ..WriteOptions::default()
+      },
+    );
+
+    assert_eq!(
+      read_resource(format!("{locales}/en/namespace_1.json")),
+      json!({
+        "HOOK_WITH_NAMESPACE": "Hook with namespace",
+        "UNUSED_KEY": "Unused",
+        "legacy": { "title": "Legacy title" }
+      })
+    );
+    assert_eq!(
+      read_resource(format!("{locales}/fr/namespace_1.json")),
+      json!({ "HOOK_WITH_NAMESPACE": "TODO" })
+    );
+  }
+
+  #[test]
+  fn remove_unused_and_sort_keys() {
+    let locales = write_resources(
+      "remove-unused",
+      "HookWithNamespace.tsx",
+      WriteOptions {
+        remove_unused: Some(true),
+        sort_keys: Some(true),
+        ..WriteOptions::default()
+      },
+    );
+
+    assert_eq!(
+      read_resource(format!("{locales}/en/namespace_1.json")),
+      json!({ "HOOK_WITH_NAMESPACE": "Hook with namespace" })
+    );
+    assert_eq!(
+      read_resource(format!("{locales}/en/translation.json")),
+      json!({})
+    );
+  }
+
+  #[test]
+  fn fill_source_language_with_default_values() {
+    let locales = write_resources(
+      "source-language",
+      "DefaultValue.tsx",
+      WriteOptions {
+        source_language: Some("en".into()),
+        ..WriteOptions::default()
+      },
+    );
+
+    let en = read_resource(format!("{locales}/en/translation.json"));
+    assert_eq!(en["DEFAULT_VALUE_INLINE"], "Save");
+    assert_eq!(en["UNUSED_DEFAULT_KEY"], "Unused default");
+
+    let zh = read_resource(format!("{locales}/zh/translation.json"));
+    assert_eq!(zh["DEFAULT_VALUE_INLINE"], "");
+  }
+
+  fn locale_options(locales: &str) -> ResourceOptions {
+    ResourceOptions {
+      path_pattern: format!("{locales}/{{{{lng}}}}/{{{{ns}}}}.json"),
+      languages: Some(vec!["en".into(), "zh".into(), "fr".into()]),
+      default_namespace: None,
+    }
+  }
+
+  #[test]
+  fn keep_resources_which_cannot_be_loaded() {
+    let locales = copy_locales("malformed");
+    let file_path = format!("{locales}/en/translation.json");
+    let malformed = "{ \"UNUSED_DEFAULT_KEY\": \"Unused default\", }\n";
+    fs::write(&file_path, malformed).unwrap();
+
+    let err = LocaleResources::load(&locale_options(&locales), KeyConfig::default())
+      .err()
+      .unwrap();
+
+    assert_eq!(err.code(), "INVALID_RESOURCE");
+    assert!(err.to_string().contains(&file_path));
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), malformed);
+  }
+
+  #[test]
+  fn report_resources_which_cannot_be_written() {
+    let (_, collector) = collect("HookWithNamespace.tsx".into(), None);
+    let locales = copy_locales("unwritable");
+    // A file where the directory of `fr` should be
+    fs::write(format!("{locales}/fr"), "").unwrap();
+    let mut resources =
+      LocaleResources::load(&locale_options(&locales), KeyConfig::default()).unwrap();
+    resources.merge_keys(
+      &collector.i18n_namespaces,
+      &collector.i18n_default_values,
+      &WriteOptions::default(),
+    );
+
+    let err = resources.write().err().unwrap();
+
+    assert_eq!(err.code(), "WRITE_RESOURCE");
+    assert!(err.to_string().contains(&format!("{locales}/fr/")));
+  }
+}
@@ -0,0 +1,128 @@
+use super::{
+  expression_end, markup_ranges, ComponentScript, ScriptBuilder, SCRIPT_BLOCK, STYLE_BLOCK,
+};
+use oxc_ast::ast::SourceType;
+use std::ops::Range;
+
+const FENCE: &str = "---";
+
+/// Extracts the frontmatter together with the expressions of the markup,
+/// `{t('key')}` and `title={t('key')}` are read in the scope of the frontmatter.
+/// `<script>` elements are bundled on their own and are skipped
+pub fn extract_script(source_text: &str) -> ComponentScript {
+  let mut builder = ScriptBuilder::new(source_text);
+
+  let markup_start = match frontmatter_range(source_text) {
+    Some(frontmatter) => {
+      builder.keep(frontmatter.clone());
+      // The closing fence ends the last statement of the frontmatter
+      builder.write(frontmatter.end..frontmatter.end + FENCE.len(), ";");
+      frontmatter.end + FENCE.len()
+    }
+    None => 0,
+  };
+
+  let markup = &source_text[markup_start..];
+  let mut elements = SCRIPT_BLOCK
+    .find_iter(markup)
+    .chain(STYLE_BLOCK.find_iter(markup))
+    .map(|element| markup_start + element.start()..markup_start + element.end())
+    .collect::<Vec<_>>();
+  elements.sort_by_key(|element| element.start);
+
+  for range in markup_ranges(markup_start..source_text.len(), &elements) {
+    scan_markup(&mut builder, source_text, range);
+  }
+
+  ComponentScript {
+    source_text: builder.finish(),
+    // Expressions of the markup are JSX
+    source_type: SourceType::tsx().with_module(true),
+  }
+}
+
+/// The code between the `---` fences opening the file
+fn frontmatter_range(source_text: &str) -> Option<Range<usize>> {
+  let start = source_text.len() - source_text.trim_start().len();
+  if !source_text[start..].starts_with(FENCE) {
+    return None;
+  }
+
+  let content_start = start + FENCE.len();
+  let end = source_text[content_start..]
+    .match_indices(FENCE)
+    .map(|(offset, _)| content_start + offset)
+    .find(|end| source_text[..*end].ends_with('\n'))?;
+
+  Some(content_start..end)
+}
+
+fn scan_markup(builder: &mut ScriptBuilder, source_text: &str, range: Range<usize>) {
+  let bytes = source_text.as_bytes();
+  let mut index = range.start;
+  let mut in_tag = false;
+
+  while index < range.end {
+    let rest = &source_text[index..range.end];
+
+    index = match bytes[index] {
+      _ if !in_tag && rest.starts_with("<!--") => {
+        rest.find("-->").map_or(range.end, |end| index + end + 3)
+      }
+      b'{' => {
+        let Some(close) = expression_end(&source_text[..range.end], index) else {
+          break;
+        };
+        builder.wrap_expression(index, index + 1..close, close);
+        close + 1
+      }
+      b'<' if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) => {
+        in_tag = true;
+        index + 1
+      }
+      b'>' => {
+        in_tag = false;
+        index + 1
+      }
+      // Quoted attribute values are text
+      quote @ (b'"' | b'\'') if in_tag => rest[1..]
+        .find(quote as char)
+        .map_or(range.end, |end| index + end + 2),
+      _ => index + rest.chars().next().map_or(1, char::len_utf8),
+    };
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::extract_script;
+
+  #[test]
+  fn keeps_frontmatter_and_expressions_in_place() {
+    let source = r#"---
+import { t } from 'i18next';
+const title = t('TITLE')
+---
+<h1 title={t('ATTRIBUTE')} class="{t('TEXT')}">{t('TEXT')}</h1>
+{/* {t('COMMENTED')} */}
+<script>console.log(t('CLIENT'))</script>
+"#;
+    let script = extract_script(source);
+
+    assert!(script.source_type.is_jsx());
+    assert_eq!(
+      script.source_text.find("const title"),
+      source.find("const title")
+    );
+    assert_eq!(
+      script.source_text.find("(t('ATTRIBUTE'))"),
+      source.find("{t('ATTRIBUTE')}")
+    );
+    assert_eq!(
+      script.source_text.find("(t('TEXT'))"),
+      source.rfind("{t('TEXT')}")
+    );
+    assert!(!script.source_text.contains("COMMENTED"));
+    assert!(!script.source_text.contains("CLIENT"));
+  }
+}
@@ -0,0 +1,255 @@
+pub mod astro;
+pub mod svelte;
+pub mod vue;
+
+use oxc_ast::ast::SourceType;
+use regex::Regex;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::LazyLock;
+
+static SCRIPT_BLOCK: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"(?s)<script\b([^>]*)>(.*?)</script\s*>").unwrap());
+static STYLE_BLOCK: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"(?s)<style\b[^>]*>.*?</style\s*>").unwrap());
+static LANG_ATTRIBUTE: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r#"\blang\s*=\s*["']?(\w+)"#).unwrap());
+
+/// The script of a component file made of several blocks, every byte outside of
+/// the code is blanked so spans, lines and columns still point into the component file
+pub struct ComponentScript {
+  pub source_text: String,
+  pub source_type: SourceType,
+}
+
+pub fn is_component_file(file_path: &str) -> bool {
+  matches!(
+    Path::new(file_path)
+      .extension()
+      .and_then(|ext| ext.to_str()),
+    Some("vue" | "svelte" | "astro")
+  )
+}
+
+pub fn extract_script(file_path: &str, source_text: &str) -> Option<ComponentScript> {
+  match Path::new(file_path).extension()?.to_str()? {
+    "vue" => Some(vue::extract_script(source_text)),
+    "svelte" => Some(svelte::extract_script(source_text)),
+    "astro" => Some(astro::extract_script(source_text)),
+    _ => None,
+  }
+}
+
+/// The source type of a `<script lang="...">` block
+fn script_source_type(lang: Option<&str>) -> SourceType {
+  match lang {
+    Some("ts") => SourceType::ts().with_module(true),
+    Some("tsx") => SourceType::tsx().with_module(true),
+    Some("jsx") => SourceType::jsx(),
+    _ => SourceType::mjs(),
+  }
+}
+
+/// The `<script>` and `<style>` blocks of a component
+struct ComponentBlocks<'a> {
+  // The first lang declared by a script block
+  lang: Option<&'a str>,
+  // The code of the script blocks
+  scripts: Vec<Range<usize>>,
+  // The whole script and style elements, which are not markup
+  elements: Vec<Range<usize>>,
+}
+
+/// Keeps the code of every `<script>` block of `range` at its place
+fn keep_script_blocks<'a>(
+  builder: &mut ScriptBuilder,
+  source_text: &'a str,
+  range: Range<usize>,
+) -> ComponentBlocks<'a> {
+  let mut blocks = ComponentBlocks {
+    lang: None,
+    scripts: vec![],
+    elements: vec![],
+  };
+
+  for captures in SCRIPT_BLOCK.captures_iter(&source_text[range.clone()]) {
+    let (Some(element), Some(attributes), Some(content)) =
+      (captures.get(0), captures.get(1), captures.get(2))
+    else {
+      continue;
+    };
+    let content = range.start + content.start()..range.start + content.end();
+
+    blocks.lang = blocks.lang.or_else(|| {
+      LANG_ATTRIBUTE
+        .captures(attributes.as_str())
+        .and_then(|lang| lang.get(1))
+        .map(|lang| {
+          let start = range.start + attributes.start();
+          &source_text[start + lang.start()..start + lang.end()]
+        })
+    });
+    // The tags around the code end the statements of the other blocks
+    builder.write(content.start - 1..content.start, ";");
+    builder.write(content.end..content.end + 1, ";");
+    builder.keep(content.clone());
+    blocks.scripts.push(content);
+    blocks
+      .elements
+      .push(range.start + element.start()..range.start + element.end());
+  }
+
+  for style in STYLE_BLOCK.find_iter(&source_text[range.clone()]) {
+    blocks
+      .elements
+      .push(range.start + style.start()..range.start + style.end());
+  }
+  blocks.elements.sort_by_key(|element| element.start);
+
+  blocks
+}
+
+/// The parts of `range` outside of the elements
+fn markup_ranges(range: Range<usize>, elements: &[Range<usize>]) -> Vec<Range<usize>> {
+  let mut ranges = vec![];
+  let mut start = range.start;
+  for element in elements {
+    if element.start > start {
+      ranges.push(start..element.start);
+    }
+    start = start.max(element.end);
+  }
+  if range.end > start {
+    ranges.push(start..range.end);
+  }
+
+  ranges
+}
+
+/// Finds the `}` closing the `{` at `open`, braces in strings are skipped
+fn expression_end(source_text: &str, open: usize) -> Option<usize> {
+  let bytes = source_text.as_bytes();
+  let mut depth = 0;
+  let mut index = open;
+
+  while index < bytes.len() {
+    match bytes[index] {
+      b'{' => depth += 1,
+      b'}' => {
+        depth -= 1;
+        if depth == 0 {
+          return Some(index);
+        }
+      }
+      quote @ (b'"' | b'\'' | b'`') => {
+        // A quote without its pair on the line is text, e.g. an apostrophe in nested markup
+        let closing = bytes[index + 1..]
+          .iter()
+          .position(|byte| *byte == quote || (quote != b'`' && *byte == b'\n'))
+          .map(|offset| index + 1 + offset);
+        if let Some(closing) = closing.filter(|closing| bytes[*closing] == quote) {
+          index = closing;
+        }
+      }
+      _ => {}
+    }
+    index += 1;
+  }
+
+  None
+}
+
+/// Builds a script out of a component source, starting from the blanked source
+/// and copying the code back to its own offsets
+pub(crate) struct ScriptBuilder<'a> {
+  source_text: &'a str,
+  bytes: Vec<u8>,
+}
+
+impl<'a> ScriptBuilder<'a> {
+  pub fn new(source_text: &'a str) -> Self {
+    let mut blanked = String::with_capacity(source_text.len());
+    for char in source_text.chars() {
+      // Keep the line breaks and the width of every character so locations do not move
+      match char.len_utf8() {
+        _ if char == '\n' || char == '\r' => blanked.push(char),
+        1 => blanked.push(' '),
+        2 => blanked.push('\u{a0}'),
+        3 => blanked.push('\u{2002}'),
+        _ => blanked.push_str("\u{a0}\u{a0}"),
+      }
+    }
+
+    Self {
+      source_text,
+      bytes: blanked.into_bytes(),
+    }
+  }
+
+  /// Keeps the code of the range at its place
+  pub fn keep(&mut self, range: Range<usize>) {
+    self.bytes[range.clone()].copy_from_slice(&self.source_text.as_bytes()[range]);
+  }
+
+  /// Writes the code over the range padded with spaces,
+  /// nothing is written when it does not fit on the same line
+  pub fn write(&mut self, range: Range<usize>, code: &str) -> bool {
+    if code.len() > range.len() || self.source_text[range.clone()].contains('\n') {
+      return false;
+    }
+
+    let (written, padding) = self.bytes[range].split_at_mut(code.len());
+    written.copy_from_slice(code.as_bytes());
+    padding.fill(b' ');
+    true
+  }
+
+  /// Keeps the expression between the braces at `open` and `close` as `(expression)`,
+  /// consecutive expressions are read as calls of each other which keeps their own calls.
+  /// Spreads and comments are not expressions and are left blank
+  pub fn wrap_expression(&mut self, open: usize, expression: Range<usize>, close: usize) {
+    let code = self.source_text[expression.clone()].trim();
+    if code.is_empty()
+      || code.starts_with("...")
+      || code.starts_with("/*")
+      || code.starts_with("//")
+    {
+      return;
+    }
+
+    self.write(open..open + 1, "(");
+    self.keep(expression);
+    self.write(close..close + 1, ")");
+  }
+
+  pub fn finish(self) -> String {
+    // Only whole characters are copied or written over ASCII bytes
+    String::from_utf8(self.bytes).unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{expression_end, ScriptBuilder};
+
+  #[test]
+  fn finds_the_closing_brace() {
+    let source = "{t('a}', { count })} {b}";
+
+    assert_eq!(expression_end(source, 0), Some(19));
+    assert_eq!(expression_end("{<p>Don't</p>}", 0), Some(13));
+  }
+
+  #[test]
+  fn blanks_without_moving_locations() {
+    let source = "<p>Ä</p>\n<script>t('KEY')</script>";
+    let start = source.find("t(").unwrap();
+    let mut builder = ScriptBuilder::new(source);
+    builder.keep(start..start + 8);
+    let script = builder.finish();
+
+    assert_eq!(script.len(), source.len());
+    assert_eq!(script.find("t('KEY')"), Some(start));
+    assert_eq!(script.lines().count(), 2);
+  }
+}
@@ -0,0 +1,140 @@
+use super::{
+  expression_end, keep_script_blocks, markup_ranges, script_source_type, ComponentScript,
+  ScriptBuilder,
+};
+use std::ops::Range;
+
+/// The blocks and tags holding an expression, with what ends the expression before the `}`
+const KEYWORD_EXPRESSIONS: &[(&str, &[&str])] = &[
+  ("#if", &[]),
+  ("#key", &[]),
+  ("#await", &[" then ", " catch "]),
+  ("#each", &[" as "]),
+  (":else if", &[]),
+  ("@html", &[]),
+  ("@render", &[]),
+  ("@const", &[]),
+];
+
+/// Extracts the `<script>` blocks together with the expressions of the markup,
+/// `{$t('key')}`, `title={$t('key')}` and `{#if $t('key')}` are read in the scope of the script
+pub fn extract_script(source_text: &str) -> ComponentScript {
+  let mut builder = ScriptBuilder::new(source_text);
+  let blocks = keep_script_blocks(&mut builder, source_text, 0..source_text.len());
+
+  for script in &blocks.scripts {
+    unprefix_stores(&mut builder, source_text, script.clone());
+  }
+  for range in markup_ranges(0..source_text.len(), &blocks.elements) {
+    scan_markup(&mut builder, source_text, range);
+  }
+
+  ComponentScript {
+    source_text: builder.finish(),
+    source_type: script_source_type(blocks.lang),
+  }
+}
+
+fn scan_markup(builder: &mut ScriptBuilder, source_text: &str, range: Range<usize>) {
+  let mut index = range.start;
+
+  while index < range.end {
+    let rest = &source_text[index..range.end];
+
+    index = if rest.starts_with("<!--") {
+      rest.find("-->").map_or(range.end, |end| index + end + 3)
+    } else if rest.starts_with('{') {
+      let Some(close) = expression_end(&source_text[..range.end], index) else {
+        break;
+      };
+      if let Some(expression) = block_expression(&source_text[index + 1..close]) {
+        let expression = index + 1 + expression.start..index + 1 + expression.end;
+        builder.wrap_expression(index, expression.clone(), close);
+        unprefix_stores(builder, source_text, expression);
+      }
+      close + 1
+    } else {
+      index + rest.chars().next().map_or(1, char::len_utf8)
+    };
+  }
+}
+
+/// The expression between the braces of a markup tag, blocks without one like `{/if}` have none
+fn block_expression(content: &str) -> Option<Range<usize>> {
+  let start = content.len() - content.trim_start().len();
+  let tag = &content[start..];
+  if !tag.starts_with(['#', ':', '@', '/']) {
+    return Some(start..content.len());
+  }
+
+  let (keyword, ends) = KEYWORD_EXPRESSIONS.iter().find(|(keyword, _)| {
+    tag
+      .strip_prefix(keyword)
+      .is_some_and(|rest| rest.starts_with(char::is_whitespace))
+  })?;
+  let start = start + keyword.len();
+  let end = ends
+    .iter()
+    .filter_map(|end| content[start..].find(end))
+    .min()
+    .map_or(content.len(), |end| start + end);
+
+  Some(start..end)
+}
+
+/// Reads `$store` as `store`, so `$t('key')` is the call of the `t` store
+fn unprefix_stores(builder: &mut ScriptBuilder, source_text: &str, range: Range<usize>) {
+  let bytes = source_text.as_bytes();
+  let mut quote = None;
+
+  for index in range.clone() {
+    match (bytes[index], quote) {
+      (b'\n', _) => quote = None,
+      (byte @ (b'"' | b'\''), None) => quote = Some(byte),
+      (byte, Some(open)) if byte == open => quote = None,
+      (b'$', None) => {
+        let is_prefix = index == range.start
+          || !(bytes[index - 1].is_ascii_alphanumeric()
+            || matches!(bytes[index - 1], b'_' | b'$' | b'.'));
+        let is_store = bytes
+          .get(index + 1)
+          .is_some_and(|byte| byte.is_ascii_alphabetic() || *byte == b'_');
+        if is_prefix && is_store {
+          builder.write(index..index + 1, " ");
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::extract_script;
+
+  #[test]
+  fn keeps_markup_expressions_in_place() {
+    let source = r#"<script lang="ts">
+  import { t } from './i18n';
+</script>
+
+<h1 title={$t('TITLE')}>{$t('TEXT')}</h1>
+{#each items as item}<p>{item}</p>{/each}
+<style>p { color: red; }</style>
+"#;
+    let script = extract_script(source);
+
+    assert!(script.source_type.is_typescript());
+    assert_eq!(
+      script.source_text.find(" t('TITLE')"),
+      source.find("$t('TITLE')")
+    );
+    assert_eq!(
+      script.source_text.find(" t('TEXT')"),
+      source.find("$t('TEXT')")
+    );
+    assert_eq!(script.source_text.find("items"), source.find("items as"));
+    assert!(!script.source_text.contains("item}"));
+    assert!(!script.source_text.contains("color"));
+  }
+}
@@ -0,0 +1,236 @@
+use super::{
+  keep_script_blocks, script_source_type, ComponentScript, ScriptBuilder, LANG_ATTRIBUTE,
+};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+static TEMPLATE_TAG: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"<(/?)template\b([^>]*)>").unwrap());
+
+/// Extracts `<script>` and `<script setup>` together with the expressions of the template,
+/// `{{ $t('key') }}`, `:title="t('key')"`, `v-t="'key'"` and `<i18n-t keypath="key">`
+/// are read as t calls in the scope of the script, `$t` is left undeclared as the global translator
+pub fn extract_script(source_text: &str) -> ComponentScript {
+  let mut builder = ScriptBuilder::new(source_text);
+  let blocks = keep_script_blocks(&mut builder, source_text, 0..source_text.len());
+
+  if let Some(range) = template_range(source_text) {
+    let mut scanner = TemplateScanner {
+      source_text,
+      builder: &mut builder,
+    };
+    scanner.scan(range);
+  }
+
+  ComponentScript {
+    source_text: builder.finish(),
+    source_type: script_source_type(blocks.lang),
+  }
+}
+
+/// The content of the root `<template>`, templates in other languages are skipped
+fn template_range(source_text: &str) -> Option<Range<usize>> {
+  let mut tags = TEMPLATE_TAG.captures_iter(source_text);
+  let root = tags.next()?;
+  let is_html = LANG_ATTRIBUTE
+    .captures(&root[2])
+    .is_none_or(|lang| &lang[1] == "html");
+  if !is_html || !root[1].is_empty() {
+    return None;
+  }
+
+  let start = root.get(0)?.end();
+  let mut depth = 1;
+  for tag in tags {
+    depth = if tag[1].is_empty() {
+      depth + 1
+    } else {
+      depth - 1
+    };
+    if depth == 0 {
+      return Some(start..tag.get(0)?.start());
+    }
+  }
+
+  None
+}
+
+fn is_translation_component(tag: &str) -> bool {
+  matches!(tag, "i18n-t" | "I18nT")
+}
+
+struct TemplateScanner<'s, 'b> {
+  source_text: &'s str,
+  builder: &'b mut ScriptBuilder<'s>,
+}
+
+impl TemplateScanner<'_, '_> {
+  fn scan(&mut self, range: Range<usize>) {
+    let mut index = range.start;
+
+    while index < range.end {
+      let rest = &self.source_text[index..range.end];
+
+      index = if rest.starts_with("<!--") {
+        rest.find("-->").map_or(range.end, |end| index + end + 3)
+      } else if rest.starts_with("{{") {
+        let Some(end) = rest.find("}}") else {
+          break;
+        };
+        // {{ expression }}
+        self.expression(
+          index..index + 2,
+          index + 2..index + end,
+          index + end..index + end + 2,
+          (";(", ")"),
+        );
+        index + end + 2
+      } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        self.tag(index, range.end)
+      } else {
+        index + rest.chars().next().map_or(1, char::len_utf8)
+      };
+    }
+  }
+
+  /// Reads the attributes of the tag starting at `start`, returns where the tag ends
+  fn tag(&mut self, start: usize, end: usize) -> usize {
+    let source_text = self.source_text;
+    let bytes = source_text.as_bytes();
+    let is_name = |byte: u8| !byte.is_ascii_whitespace() && !matches!(byte, b'=' | b'>' | b'/');
+
+    let mut index = start + 1;
+    while index < end && is_name(bytes[index]) {
+      index += 1;
+    }
+    let tag = &source_text[start + 1..index];
+
+    while index < end {
+      match bytes[index] {
+        b'>' => return index + 1,
+        byte if byte.is_ascii_whitespace() || byte == b'/' => index += 1,
+        _ => {
+          let name_start = index;
+          while index < end && is_name(bytes[index]) {
+            index += 1;
+          }
+          let name = &source_text[name_start..index];
+
+          while index < end && bytes[index].is_ascii_whitespace() {
+            index += 1;
+          }
+          if index >= end || bytes[index] != b'=' {
+            continue;
+          }
+          index += 1;
+          while index < end && bytes[index].is_ascii_whitespace() {
+            index += 1;
+          }
+
+          let quote = bytes
+            .get(index)
+            .copied()
+            .filter(|byte| matches!(byte, b'"' | b'\''));
+          let Some(quote) = quote else {
+            // Unquoted values never hold expressions worth reading
+            while index < end && is_name(bytes[index]) {
+              index += 1;
+            }
+            continue;
+          };
+          let value_start = index + 1;
+          let Some(value_end) = source_text[value_start..end]
+            .find(quote as char)
+            .map(|offset| value_start + offset)
+          else {
+            return end;
+          };
+
+          self.attribute(tag, name, name_start, value_start..value_end);
+          index = value_end + 1;
+        }
+      }
+    }
+
+    end
+  }
+
+  fn attribute(&mut self, tag: &str, name: &str, name_start: usize, value: Range<usize>) {
+    let prefix = name_start..value.start;
+    let suffix = value.end..value.end + 1;
+
+    let wrapper = match name {
+      // <i18n-t keypath="key">
+      "keypath" if is_translation_component(tag) => {
+        let quote = &self.source_text[value.start - 1..value.start];
+        let code = format!(";$t({quote}{}{quote})", &self.source_text[value.clone()]);
+        self.builder.write(name_start..suffix.end, &code);
+        return;
+      }
+      ":keypath" | "v-bind:keypath" if is_translation_component(tag) => (";$t(", ")"),
+      // v-t="'key'"
+      "v-t" => (";$t(", ")"),
+      // Those hold patterns rather than expressions
+      "v-for" | "v-slot" => return,
+      _ if name.starts_with('#') || name.starts_with("v-slot:") => return,
+      // Handlers may be statements
+      _ if name.starts_with('@') || name.starts_with("v-on:") => (";{", "}"),
+      _ if name.starts_with(':') || name.starts_with("v-") => (";(", ")"),
+      _ => return,
+    };
+
+    self.expression(prefix, value, suffix, wrapper);
+  }
+
+  /// Keeps the expression at its place, wrapped by writing over the syntax around it
+  fn expression(
+    &mut self,
+    prefix: Range<usize>,
+    expression: Range<usize>,
+    suffix: Range<usize>,
+    (open, close): (&str, &str),
+  ) {
+    let code = &self.source_text[expression.clone()];
+    if code.trim().is_empty() || !self.builder.write(prefix, open) {
+      return;
+    }
+
+    self.builder.keep(expression);
+    self.builder.write(suffix, close);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::extract_script;
+
+  #[test]
+  fn keeps_template_expressions_in_place() {
+    let source = r#"<template>
+  <p :title="$t('TITLE')">{{ t('TEXT') }}</p>
+  <i18n-t keypath="KEYPATH" tag="p" />
+</template>
+<script setup lang="ts">
+const { t } = useI18n();
+</script>
+"#;
+    let script = extract_script(source);
+
+    assert!(script.source_type.is_typescript());
+    assert_eq!(
+      script.source_text.find("$t('TITLE')"),
+      source.find("$t('TITLE')")
+    );
+    assert_eq!(
+      script.source_text.find("t('TEXT')"),
+      source.find("t('TEXT')")
+    );
+    assert!(script.source_text.contains(r#";$t("KEYPATH")"#));
+    assert_eq!(
+      script.source_text.find("const { t }"),
+      source.find("const { t }")
+    );
+    assert_eq!(script.source_text.len(), source.len());
+  }
+}
@@ -0,0 +1,256 @@
+use crate::collector::key_detail::{KeyLocation, LineIndex};
+use crate::config::ConfigError;
+use crate::diagnostics::{Diagnostic, DiagnosticSeverity, Diagnostics};
+use crate::node::i18n_types::I18nType;
+use rayon::prelude::*;
+use regex::{Captures, Regex};
+use std::collections::BTreeSet;
+use std::fs;
+
+// A quoted key, `'key'` or `"key"`
+const QUOTED_KEY: &str = r#"(?:'(?P<single>[^'\n]*)'|"(?P<double>[^"\n]*)")"#;
+
+/// Reads keys out of files which are not JavaScript, e.g. Angular or Handlebars templates
+#[napi(object)]
+pub struct TemplateExtractor {
+  /// Glob patterns of the template files, e.g. `src/app/*.html`
+  pub files: Vec<String>,
+  /// Pipes translating the quoted key before them, `{{ 'key' | translate }}`, defaults to `["translate"]`
+  pub pipes: Option<Vec<String>>,
+  /// Helpers called with a quoted key, `{{t 'key'}}` or `{{ i18n.t('key') }}`, defaults to `["t", "i18n.t"]`
+  pub helpers: Option<Vec<String>>,
+  /// Regular expressions matching a key, the key is read from the `key` group or the first group
+  /// and the namespace from the `ns` group
+  pub patterns: Option<Vec<String>>,
+}
+
+/// A key read from a template, `namespace` is only set by a pattern with a `ns` group
+pub struct TemplateKey {
+  pub namespace: Option<String>,
+  pub key: String,
+  pub location: KeyLocation,
+}
+
+/// A compiled rule of an extractor
+struct TemplateRule {
+  regex: Regex,
+  // Groups the key may be captured by, the first one matching wins
+  key_groups: Vec<&'static str>,
+}
+
+impl TemplateRule {
+  fn read_key<'t>(&self, captures: &Captures<'t>) -> Option<regex::Match<'t>> {
+    if self.key_groups.is_empty() {
+      return captures.name("key").or_else(|| captures.get(1));
+    }
+    self
+      .key_groups
+      .iter()
+      .find_map(|group| captures.name(group))
+  }
+}
+
+impl TemplateExtractor {
+  /// Reads the keys of every file matching the extractor globs,
+  /// the files which cannot be read are reported to `diagnostics`
+  pub fn extract(&self, diagnostics: &Diagnostics) -> Result<Vec<TemplateKey>, ConfigError> {
+    let rules = self.compile_rules()?;
+
+    let keys = self
+      .find_files()
+      .par_iter()
+      .flat_map(|file_path| match fs::read_to_string(file_path) {
+        Ok(source_text) => extract_keys(file_path, &source_text, &rules),
+        Err(err) => {
+          log::warn!("[i18n-scanner-rs] Unable to read template at {file_path}: {err}");
+          diagnostics.push(Diagnostic::file(
+            file_path,
+            format!("Unable to read the template: {err}"),
+            DiagnosticSeverity::Error,
+          ));
+          vec![]
+        }
+      })
+      .collect();
+
+    Ok(keys)
+  }
+
+  /// The files matching the globs, each file once and sorted so the keys keep their order
+  fn find_files(&self) -> BTreeSet<String> {
+    self
+      .files
+      .iter()
+      .flat_map(|pattern| match glob::glob(pattern) {
+        Ok(paths) => paths
+          .flatten()
+          .filter(|path| path.is_file())
+          .filter_map(|path| path.to_str().map(String::from))
+          .collect(),
+        Err(err) => {
+          log::warn!("[i18n-scanner-rs] Invalid template glob '{pattern}': {err}");
+          vec![]
+        }
+      })
+      .collect()
+  }
+
+  /// The rules of the pipes, helpers and patterns, a rule which is not a valid regular
+  /// expression is an error at its field, e.g. `patterns[0]`
+  fn compile_rules(&self) -> Result<Vec<TemplateRule>, ConfigError> {
+    let pipes = self
+      .pipes
+      .clone()
+      .unwrap_or_else(|| vec!["translate".to_string()]);
+    let helpers = self
+      .helpers
+      .clone()
+      .unwrap_or_else(|| vec!["t".to_string(), "i18n.t".to_string()]);
+    let quoted_key_groups = || vec!["single", "double"];
+
+    let pipe_rules = pipes.iter().enumerate().map(|(index, pipe)| {
+      (
+        format!("pipes[{index}]"),
+        format!(r"{QUOTED_KEY}\s*\|\s*{}\b", regex::escape(pipe)),
+        quoted_key_groups(),
+      )
+    });
+    // The helper is not part of a longer name, `t` does not match `i18n.t` or `format`
+    let helper_rules = helpers.iter().enumerate().map(|(index, helper)| {
+      (
+        format!("helpers[{index}]"),
+        format!(
+          r"(?:^|[^\w.$]){}(?:\s*\(\s*|\s+){QUOTED_KEY}",
+          regex::escape(helper)
+        ),
+        quoted_key_groups(),
+      )
+    });
+    let pattern_rules = self
+      .patterns
+      .iter()
+      .flatten()
+      .enumerate()
+      .map(|(index, pattern)| (format!("patterns[{index}]"), pattern.clone(), vec![]));
+
+    pipe_rules
+      .chain(helper_rules)
+      .chain(pattern_rules)
+      .map(|(field, pattern, key_groups)| {
+        let regex = Regex::new(&pattern).map_err(|err| ConfigError {
+          field,
+          message: format!("is not a valid regular expression: {err}"),
+        })?;
+        Ok(TemplateRule { regex, key_groups })
+      })
+      .collect()
+  }
+}
+
+fn extract_keys(file_path: &str, source_text: &str, rules: &[TemplateRule]) -> Vec<TemplateKey> {
+  let line_index = LineIndex::new(source_text);
+  let mut keys = vec![];
+
+  for rule in rules {
+    for captures in rule.regex.captures_iter(source_text) {
+      let Some(key) = rule.read_key(&captures).filter(|key| !key.is_empty()) else {
+        continue;
+      };
+      let (line, column) = line_index.line_column(key.start() as u32);
+
+      keys.push(TemplateKey {
+        namespace: captures.name("ns").map(|ns| ns.as_str().to_string()),
+        key: key.as_str().to_string(),
+        location: KeyLocation {
+          file_path: file_path.to_string(),
+          line,
+          column,
+          r#type: I18nType::TMethod,
+        },
+      });
+    }
+  }
+  keys.sort_by_key(|key| (key.location.line, key.location.column));
+
+  keys
+}
+
+#[cfg(test)]
+mod tests {
+  use super::TemplateExtractor;
+  use crate::analyzer::test_utils::test_path;
+  use crate::diagnostics::Diagnostics;
+
+  fn extractor(files: Vec<String>) -> TemplateExtractor {
+    TemplateExtractor {
+      files,
+      pipes: None,
+      helpers: None,
+      patterns: None,
+    }
+  }
+
+  #[test]
+  fn reads_pipes_and_helpers() {
+    let keys = extractor(vec![test_path("templates/page.html")])
+      .extract(&Diagnostics::default())
+      .unwrap();
+    let keys: Vec<&str> = keys.iter().map(|key| key.key.as_str()).collect();
+
+    assert_eq!(
+      keys,
+      vec![
+        "HTML_PIPE",
+        "HTML_PIPE_ARGS",
+        "HTML_HELPER",
+        "common:HTML_NS"
+      ]
+    );
+  }
+
+  #[test]
+  fn reads_custom_patterns() {
+    let keys = TemplateExtractor {
+      helpers: Some(vec!["t".to_string()]),
+      patterns: Some(vec![
+        r#"\{\{i18n-key "(?P<ns>\w+)" "(?P<key>[^"]+)"\}\}"#.to_string()
+      ]),
+      ..extractor(vec![format!("{}/*.hbs", test_path("templates"))])
+    }
+    .extract(&Diagnostics::default())
+    .unwrap();
+
+    let keys: Vec<(Option<&str>, &str)> = keys
+      .iter()
+      .map(|key| (key.namespace.as_deref(), key.key.as_str()))
+      .collect();
+    assert_eq!(
+      keys,
+      vec![(None, "HBS_HELPER"), (Some("namespace_hbs"), "HBS_PATTERN"),]
+    );
+  }
+
+  #[test]
+  fn locates_keys() {
+    let keys = extractor(vec![test_path("templates/page.html")])
+      .extract(&Diagnostics::default())
+      .unwrap();
+
+    assert_eq!(keys[0].location.line, 2);
+    assert_eq!(keys[0].location.column, 11);
+  }
+
+  #[test]
+  fn rejects_invalid_patterns() {
+    let err = TemplateExtractor {
+      patterns: Some(vec![r"(?P<key>\w+".to_string()]),
+      ..extractor(vec![test_path("templates/page.html")])
+    }
+    .extract(&Diagnostics::default())
+    .err()
+    .unwrap();
+
+    assert_eq!(err.field, "patterns[0]");
+    assert!(err.message.starts_with("is not a valid regular expression"));
+  }
+}
@@ -1,27 +1,41 @@
+use crate::module_cache::ModuleCache;
 use crate::node::node::Node;
 use log::debug;
 use oxc_ast::ast::{
   BinaryOperator, BindingPattern, CallExpression, Declaration, Expression, ObjectPropertyKind,
-  PropertyKey, SourceType, Statement,
+  PropertyKey, Statement, StringLiteral, VariableDeclarator,
 };
 use oxc_ast::AstKind;
 use oxc_semantic::{AstNode, Semantic};
 use oxc_syntax::reference::ReferenceId;
-use std::rc::Rc;
+use oxc_syntax::symbol::SymbolId;
+use std::sync::Arc;
 
 pub struct WalkerUtils<'a> {
   pub semantic: &'a Semantic<'a>,
-  pub node: Rc<Node>,
+  pub node: Arc<Node>,
+  pub module_cache: ModuleCache,
 }
 
 impl<'a> WalkerUtils<'a> {
-  pub fn new(semantic: &'a Semantic<'a>, node: Rc<Node>) -> Self {
-    Self { semantic, node }
+  pub fn new(semantic: &'a Semantic<'a>, node: Arc<Node>, module_cache: ModuleCache) -> Self {
+    Self {
+      semantic,
+      node,
+      module_cache,
+    }
   }
 
   pub fn read_str_expression(&self, expr: &Expression) -> Option<String> {
-    match expr {
+    self.read_str_operand(expr, false)
+  }
+
+  /// Numbers are only read as operands of a concatenation, `'KEY_' + 1`, so `t(1)` is no key
+  fn read_str_operand(&self, expr: &Expression, is_operand: bool) -> Option<String> {
+    // Fold `('KEY' as const)` or `KEY!` down to the value expression since the AST is not minified
+    match expr.get_inner_expression() {
       Expression::StringLiteral(s) => Some(s.value.to_string()),
+      Expression::NumericLiteral(n) => is_operand.then(|| number_to_string(n.value)),
       Expression::TemplateLiteral(tpl) => {
         // Handle template literal by concatenating quasis and expressions
         if tpl.quasis.len() == 1 && tpl.expressions.is_empty() {
@@ -34,7 +48,7 @@ impl<'a> WalkerUtils<'a> {
             result.push_str(&quasi.value.raw);
             if i < tpl.expressions.len() {
               // Try to resolve the expression to a string
-              if let Some(expr_str) = self.read_str_expression(&tpl.expressions[i]) {
+              if let Some(expr_str) = self.read_str_operand(&tpl.expressions[i], true) {
                 result.push_str(&expr_str);
               } else {
                 // If we can't resolve the expression, return None
@@ -51,7 +65,7 @@ impl<'a> WalkerUtils<'a> {
           match node.kind() {
             AstKind::VariableDeclarator(var) => {
               if let Some(init) = &var.init {
-                return self.read_str_expression(init);
+                return self.read_str_operand(init, is_operand);
               }
               // TODO: Handle define and after assignment
               //  let key;
@@ -112,8 +126,8 @@ impl<'a> WalkerUtils<'a> {
         if bin_expr.operator == BinaryOperator::Addition {
           // Handle string concatenation
           if let (Some(left), Some(right)) = (
-            self.read_str_expression(&bin_expr.left),
-            self.read_str_expression(&bin_expr.right),
+            self.read_str_operand(&bin_expr.left, true),
+            self.read_str_operand(&bin_expr.right, true),
           ) {
             return Some(format!("{}{}", left, right));
           }
@@ -136,6 +150,49 @@ impl<'a> WalkerUtils<'a> {
     }
   }
 
+  /// i18n.getFixedT(lng, 'namespace', 'keyPrefix'), returns the namespace and key prefix
+  pub fn read_fixed_t_arguments(&self, call: &CallExpression) -> (Option<String>, Option<String>) {
+    let read_argument = |index: usize| {
+      call
+        .arguments
+        .get(index)
+        .and_then(|arg| arg.as_expression())
+        .and_then(|expr| match expr {
+          Expression::ArrayExpression(array_expr) => {
+            self.read_first_namespace_from_array(array_expr)
+          }
+          _ => self.read_str_expression(expr),
+        })
+    };
+
+    (read_argument(1), read_argument(2))
+  }
+
+  /// useTranslation('namespace', { keyPrefix: 'page.header' })
+  pub fn read_hook_key_prefix(&self, call: &CallExpression) -> Option<String> {
+    let Some(Expression::ObjectExpression(obj_expr)) =
+      call.arguments.get(1).and_then(|arg| arg.as_expression())
+    else {
+      return None;
+    };
+
+    obj_expr.properties.iter().find_map(|prop| {
+      let ObjectPropertyKind::ObjectProperty(object_prop) = prop else {
+        return None;
+      };
+
+      let PropertyKey::StaticIdentifier(key) = &object_prop.key else {
+        return None;
+      };
+
+      if key.name != "keyPrefix" {
+        return None;
+      }
+
+      self.read_str_expression(&object_prop.value)
+    })
+  }
+
   pub fn read_hook_namespace_argument(&self, call: &CallExpression) -> Option<String> {
     let Some(arg) = call.arguments.get(0) else {
       return None;
@@ -180,6 +237,105 @@ impl<'a> WalkerUtils<'a> {
     self.read_str_expression(expr)
   }
 
+  /// The source of `require('source')` when `require` is the CommonJS global
+  pub fn read_require_source<'b>(&self, expr: &'b Expression<'a>) -> Option<&'b StringLiteral<'a>> {
+    match expr.get_inner_expression() {
+      Expression::CallExpression(call) => self.read_require_call(call),
+      _ => None,
+    }
+  }
+
+  pub fn read_require_call<'b>(
+    &self,
+    call: &'b CallExpression<'a>,
+  ) -> Option<&'b StringLiteral<'a>> {
+    let Expression::Identifier(callee) = &call.callee else {
+      return None;
+    };
+    if callee.name != "require" || call.arguments.len() != 1 {
+      return None;
+    }
+    let is_global = self
+      .semantic
+      .scoping()
+      .get_reference(callee.reference_id())
+      .symbol_id()
+      .is_none();
+
+    match call.arguments[0].as_expression()? {
+      Expression::StringLiteral(source) if is_global => Some(source),
+      _ => None,
+    }
+  }
+
+  /// The member a binding of `const { name: local } = require('source')` reads,
+  /// `const local = require('source')` binds the whole module like a default import
+  pub fn read_required_name(
+    &self,
+    var: &VariableDeclarator,
+    symbol_id: SymbolId,
+  ) -> Option<String> {
+    match &var.id {
+      BindingPattern::BindingIdentifier(_) => Some("default".into()),
+      BindingPattern::ObjectPattern(obj) => obj
+        .properties
+        .iter()
+        .find(|prop| match &prop.value {
+          BindingPattern::BindingIdentifier(ident) => ident.symbol_id() == symbol_id,
+          _ => false,
+        })
+        .and_then(|prop| prop.key.static_name())
+        .map(|name| name.to_string()),
+      _ => None,
+    }
+  }
+
+  /// References of a name the file uses without declaring it, e.g. `$t` of Vue templates
+  pub fn global_references(&self, name: &str) -> Vec<ReferenceId> {
+    self
+      .semantic
+      .scoping()
+      .root_unresolved_references()
+      .iter()
+      .find(|(global, _)| global.as_str() == name)
+      .map(|(_, references)| references.to_vec())
+      .unwrap_or_default()
+  }
+
+  /// Calls of a translator the file uses without declaring it, `$t('key')` of Vue templates
+  /// or `this.$t('key')` of an Options API component
+  pub fn global_t_calls(&self, name: &str) -> Vec<&'a CallExpression<'a>> {
+    let nodes = self.semantic.nodes();
+    let global_calls = self
+      .global_references(name)
+      .into_iter()
+      .filter_map(|reference_id| {
+        let node_id = self
+          .semantic
+          .scoping()
+          .get_reference(reference_id)
+          .node_id();
+        match nodes.parent_node(node_id).kind() {
+          AstKind::CallExpression(call) => Some(call),
+          _ => None,
+        }
+      });
+    let this_calls = nodes.iter().filter_map(|node| match node.kind() {
+      AstKind::CallExpression(call) => match &call.callee {
+        Expression::StaticMemberExpression(member)
+          if matches!(member.object, Expression::ThisExpression(_))
+            && member.property.name == name =>
+        {
+          Some(call)
+        }
+        _ => None,
+      },
+      _ => None,
+    });
+
+    global_calls.chain(this_calls).collect()
+  }
+
   pub fn get_var_defined_node(&self, ref_id: ReferenceId) -> Option<&AstNode<'a>> {
     self
       .semantic
@@ -291,21 +447,10 @@ impl<'a> WalkerUtils<'a> {
     file_path: &str,
     constant_name: &str,
   ) -> Option<String> {
-    use oxc_allocator::Allocator;
-    use oxc_parser::Parser;
-    use std::fs;
-
-    // Read the file content
-    let source_text = fs::read_to_string(file_path).ok()?;
-
-    // Parse the file
-    let allocator = Allocator::default();
-    let source_type = SourceType::from_path(file_path).unwrap_or_default();
-    let parser = Parser::new(&allocator, &source_text, source_type);
-    let program = parser.parse().program;
+    let module = self.module_cache.get(file_path)?;
 
     // Look for export const declarations
-    for stmt in &program.body {
+    for stmt in &module.program().body {
       match stmt {
         Statement::ExportNamedDeclaration(export_decl) => {
           if let Some(decl) = &export_decl.declaration {
@@ -345,3 +490,12 @@ impl<'a> WalkerUtils<'a> {
     }
   }
 }
+
+/// Stringify a number the way JS does for integers, e.g. `1` rather than `1.0`
+pub fn number_to_string(value: f64) -> String {
+  if value.fract() == 0.0 && value.abs() < 1e21 {
+    format!("{}", value as i64)
+  } else {
+    value.to_string()
+  }
+}
@@ -0,0 +1,5 @@
+const { useTranslation } = require('react-i18next');
+
+const useCommonT = () => useTranslation('namespace_2');
+
+module.exports = { useCommonT };
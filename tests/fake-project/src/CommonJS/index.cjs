@@ -0,0 +1,19 @@
+const i18next = require('i18next');
+const { t } = require('i18next');
+const { useCommonT } = require('./hook.cjs');
+const { useTranslation } = require('react-i18next');
+
+function Title() {
+  const { t: translate } = useTranslation();
+  return translate('CJS_HOOK');
+}
+
+function Notice() {
+  const { t: translate } = useCommonT();
+  return translate('CJS_CUSTOM_HOOK');
+}
+
+exports.Title = Title;
+exports.Notice = Notice;
+exports.label = t('CJS_T');
+exports.title = i18next.t('CJS_INSTANCE');
@@ -0,0 +1,3 @@
+import { t } from 'i18next';
+
+export const label: string = t('MTS_T');
@@ -0,0 +1,16 @@
+import { useTranslation, Trans } from 'react-i18next';
+
+const App = ({ name }: { name: string }) => {
+  const { t } = useTranslation();
+  return (
+    <div>
+      <h1>{t('DEFAULT_VALUE_INLINE', 'Save')}</h1>
+      <h2>{t('DEFAULT_VALUE_OPTION', { defaultValue: 'Cancel' })}</h2>
+      <Trans i18nKey="DEFAULT_VALUE_TRANS">
+        Hello <b>{{ name }}</b>, you have <strong>new</strong> messages.<br />
+      </Trans>
+    </div>
+  );
+};
+
+export default App;
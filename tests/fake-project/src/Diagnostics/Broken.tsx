@@ -0,0 +1,7 @@
+import { useTranslation } from 'react-i18next';
+
+export const Broken = () => {
+  const { t } = useTranslation();
+
+  return t('DIAGNOSTICS_BROKEN') +;
+};
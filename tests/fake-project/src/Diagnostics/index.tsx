@@ -0,0 +1,10 @@
+import { readFileSync } from 'fs';
+import { useTranslation } from 'react-i18next';
+import { title } from './missing';
+import { Broken } from './Broken';
+
+export const Page = () => {
+  const { t } = useTranslation();
+
+  return [t('DIAGNOSTICS_PAGE', { title }), Broken, readFileSync];
+};
@@ -0,0 +1,5 @@
+import i18next from 'i18next';
+
+export const t = i18next.getFixedT(null, 'namespace_1', 'welcome');
+
+export const subject = t('FIXED_T_SUBJECT');
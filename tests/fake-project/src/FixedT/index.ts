@@ -0,0 +1,6 @@
+import { i18n } from 'react-i18next';
+import { t, subject } from './emails';
+
+const tNotice = i18n.getFixedT(null, 'namespace_2');
+
+export const email = [subject, t('FIXED_T_BODY'), tNotice('FIXED_T_NOTICE')];
@@ -0,0 +1,6 @@
+// `usePage` is only known as a hook once `page.ts` is walked again for `useBase`
+import { useLayout, usePage } from './page';
+
+export const useApp = () => usePage();
+
+export const layout = useLayout;
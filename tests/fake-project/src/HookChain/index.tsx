@@ -0,0 +1,9 @@
+import { useApp } from './app';
+
+const Page = () => {
+  const { t } = useApp();
+
+  return <h1>{t('HOOK_CHAIN')}</h1>;
+};
+
+export default Page;
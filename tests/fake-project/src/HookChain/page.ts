@@ -0,0 +1,6 @@
+import { useTranslation } from 'react-i18next';
+import { useBase } from './base';
+
+export const useLayout = () => useTranslation();
+
+export const usePage = () => useBase();
@@ -0,0 +1,10 @@
+import { useTranslation } from 'react-i18next';
+
+const App = () => {
+  const { i18n } = useTranslation();
+  const hasTitle = i18n.exists('HOOK_KEY_METHOD_EXISTS');
+  const resource = i18n.getResource('en', 'namespace_1', 'HOOK_KEY_METHOD_RESOURCE');
+  return <div>{hasTitle && resource}</div>;
+};
+
+export default App;
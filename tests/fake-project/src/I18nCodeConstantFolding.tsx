@@ -0,0 +1,18 @@
+import { useTranslation } from 'react-i18next'
+
+const PREFIX = 'I18N_CODE' as const
+const VERSION = 2
+
+const App = () => {
+  const { t } = useTranslation()
+  return (
+    <h1>
+      {t((PREFIX + '_CONSTANT_FOLDING_V' + VERSION) as string)}
+      {/* A bare number is no key */}
+      {t(VERSION as any)}
+      {t(1 as any)}
+    </h1>
+  )
+}
+
+export default App
@@ -0,0 +1,6 @@
+import i18next from 'i18next';
+
+export const hasTitle = i18next.exists('KEY_METHOD_EXISTS');
+export const resource = i18next.getResource('en', 'namespace_1', 'KEY_METHOD_RESOURCE');
+// Only references a namespace
+export const hasBundle = i18next.hasResourceBundle('en', 'namespace_2');
@@ -0,0 +1,12 @@
+import { useTranslation } from 'react-i18next';
+
+const App = () => {
+  const { t } = useTranslation('namespace_1', { keyPrefix: 'page.header' });
+  return (
+    <div>
+      <h1>{t('KEY_PREFIX_TITLE')}</h1>
+    </div>
+  );
+};
+
+export default App;
@@ -0,0 +1,19 @@
+import { useTranslation } from 'react-i18next';
+import * as reactI18next from 'react-i18next';
+
+const App = () => {
+  const { t, i18n } = useTranslation('namespace_1', { keyPrefix: 'page' });
+  return (
+    <div>
+      <h1>{t('KEY_PREFIX_T')}</h1>
+      <h2>{i18n.exists('KEY_PREFIX_EXISTS') && i18n.t('KEY_PREFIX_INSTANCE_T')}</h2>
+    </div>
+  );
+};
+
+export const Namespaced = () => {
+  const { i18n } = reactI18next.useTranslation('namespace_1', { keyPrefix: 'page' });
+  return <p>{i18n.t('KEY_PREFIX_NAMESPACE_IMPORT_T')}</p>;
+};
+
+export default App;
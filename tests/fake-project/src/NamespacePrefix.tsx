@@ -0,0 +1,12 @@
+import { useTranslation } from 'react-i18next';
+
+const App = () => {
+  const { t } = useTranslation('namespace_1');
+  return (
+    <div>
+      <h1>{t('namespace_2:NAMESPACE_PREFIX')}</h1>
+    </div>
+  );
+};
+
+export default App;
@@ -0,0 +1,14 @@
+import { Trans, useTranslation } from 'react-i18next';
+
+const App = ({ count }: { count: number }) => {
+  const { t } = useTranslation();
+  return (
+    <div>
+      <h1>{t('PLURAL_ITEM', { count })}</h1>
+      <h2>{t('CONTEXT_FRIEND', { context: 'male' })}</h2>
+      <Trans i18nKey="TRANS_PLURAL_ITEM" count={count} />
+    </div>
+  );
+};
+
+export default App;
@@ -1,10 +1,14 @@
 import { useTranslation } from 'react-i18next'
 import { POST_COLLECTOR_KEY_ALIAS } from './reexport'
+import { POST_COLLECTOR_NUMBER, POST_COLLECTOR_VERSIONED_KEY } from './constants'
 
 const PostCollectorCrossFile = () => {
   const { t } = useTranslation()
-
-  return <p>{t(POST_COLLECTOR_KEY_ALIAS)}</p>
+  return <p>{t(POST_COLLECTOR_KEY_ALIAS)}
+    {t(POST_COLLECTOR_VERSIONED_KEY)}
+    {/* A bare number is no key */}
+    {t(POST_COLLECTOR_NUMBER)}
+  </p>
 }
 
 export default PostCollectorCrossFile
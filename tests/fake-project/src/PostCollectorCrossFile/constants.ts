@@ -1 +1,3 @@
 export const POST_COLLECTOR_BASE_KEY = 'POST_COLLECTOR_CROSS_FILE'
+export const POST_COLLECTOR_NUMBER = 5
+export const POST_COLLECTOR_VERSIONED_KEY = 'POST_COLLECTOR_V' + POST_COLLECTOR_NUMBER
@@ -0,0 +1,16 @@
+import { Trans } from 'react-i18next';
+
+const App = ({ name }: { name: string }) => {
+  return (
+    <div>
+      <Trans>
+        Hello <strong>{{ name }}</strong>
+      </Trans>
+      <Trans>
+        Welcome <strong>back</strong>
+      </Trans>
+    </div>
+  );
+};
+
+export default App;
@@ -0,0 +1,15 @@
+import { useI18n, useTranslation } from 'react-i18next';
+
+const App = () => {
+  const { t } = useTranslation();
+  // Only vue-i18n exports the `useI18n` hook
+  const { t: translate } = useI18n();
+  return (
+    <div>
+      {t('USE_TRANSLATION_KEY')}
+      {translate('NOT_A_HOOK_KEY')}
+    </div>
+  );
+};
+
+export default App;
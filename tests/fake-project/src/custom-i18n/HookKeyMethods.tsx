@@ -0,0 +1,8 @@
+import { useTranslation } from '@custom/i18n';
+
+const App = () => {
+  const { i18n } = useTranslation();
+  return <div>{i18n.hasTranslation('CUSTOM_HOOK_KEY_METHOD') && 'known'}</div>;
+};
+
+export default App;
@@ -0,0 +1,3 @@
+import { i18n } from '@custom/i18n';
+
+export const known = i18n.hasTranslation('CUSTOM_KEY_METHOD');
@@ -0,0 +1,3 @@
+import { translate } from '@custom/i18n';
+
+export const items = translate('namespace_1', 'TRANSLATE_ARGUMENTS', { count: 2 });
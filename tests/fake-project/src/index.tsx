@@ -27,6 +27,7 @@ import UseTranslationNsArray from './UseTranslationNsArray'
 import TransNsComp from './TransNsComp'
 import TranslationNsComp from './TranslationNsComp'
 import PostCollectorCrossFile from './PostCollectorCrossFile/Component'
+import I18nCodeConstantFolding from './I18nCodeConstantFolding'
 
 init()
 
@@ -61,6 +62,7 @@ const Entry = () => {
       <TransNsComp />
       <TranslationNsComp />
       <PostCollectorCrossFile />
+      <I18nCodeConstantFolding />
     </>
   )
 }
@@ -18,6 +18,7 @@ describe('I18n-scanner-rs', () => {
         "default": [
           "GLOBAL_T",
           "HOC_COMPONENT",
+          "I18N_CODE_CONSTANT_FOLDING_V2",
           "I18N_CODE_CROSS_FILE",
           "I18N_CODE_DYNAMIC_hello",
           "I18N_CODE_DYNAMIC_world",